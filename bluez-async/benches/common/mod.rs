@@ -0,0 +1,94 @@
+//! Signals shared by the event benchmarks.
+
+use bluez_async::uuid_from_u16;
+use dbus::arg::{PropMap, RefArg, Variant};
+use dbus::message::SignalArgs;
+use dbus::nonblock::stdintf::org_freedesktop_dbus::{
+    ObjectManagerInterfacesAdded, PropertiesPropertiesChanged,
+};
+use dbus::Message;
+use std::collections::HashMap;
+
+const DEVICES: usize = 100;
+
+fn device_path(i: usize) -> String {
+    format!("/org/bluez/hci0/dev_00_11_22_33_44_{:02X}", i)
+}
+
+fn properties_changed(path: String, interface_name: &str, changed_properties: PropMap) -> Message {
+    PropertiesPropertiesChanged {
+        interface_name: interface_name.to_string(),
+        changed_properties,
+        invalidated_properties: vec![],
+    }
+    .to_emit_message(&path.into())
+}
+
+/// Build a mix of the signals BlueZ sends while scanning and connected to devices: a device being
+/// discovered, then RSSI, manufacturer data, service data and characteristic value changes for
+/// each of a number of devices.
+pub fn messages() -> Vec<Message> {
+    let mut messages = vec![];
+    for i in 0..DEVICES {
+        let mut device_properties: PropMap = HashMap::new();
+        let services = vec![uuid_from_u16(0x1800 + i as u16 % 4).to_string()];
+        device_properties.insert("UUIDs".to_string(), Variant(Box::new(services)));
+        let mut interfaces = HashMap::new();
+        interfaces.insert("org.bluez.Device1".to_string(), device_properties);
+        messages.push(
+            ObjectManagerInterfacesAdded {
+                object: device_path(i).into(),
+                interfaces,
+            }
+            .to_emit_message(&"/".into()),
+        );
+
+        let mut rssi: PropMap = HashMap::new();
+        rssi.insert("RSSI".to_string(), Variant(Box::new(-70i16)));
+        messages.push(properties_changed(
+            device_path(i),
+            "org.bluez.Device1",
+            rssi,
+        ));
+
+        let manufacturer_data: HashMap<u16, Variant<Box<dyn RefArg>>> = vec![(
+            i as u16,
+            Variant(Box::new(vec![i as u8; 24]) as Box<dyn RefArg>),
+        )]
+        .into_iter()
+        .collect();
+        let mut changed: PropMap = HashMap::new();
+        changed.insert(
+            "ManufacturerData".to_string(),
+            Variant(Box::new(manufacturer_data)),
+        );
+        messages.push(properties_changed(
+            device_path(i),
+            "org.bluez.Device1",
+            changed,
+        ));
+
+        let service_data: PropMap = vec![(
+            uuid_from_u16(0x1800).to_string(),
+            Variant(Box::new(vec![i as u8; 20]) as Box<dyn RefArg>),
+        )]
+        .into_iter()
+        .collect();
+        let mut changed: PropMap = HashMap::new();
+        changed.insert("ServiceData".to_string(), Variant(Box::new(service_data)));
+        messages.push(properties_changed(
+            device_path(i),
+            "org.bluez.Device1",
+            changed,
+        ));
+
+        let mut value: PropMap = HashMap::new();
+        value.insert("Value".to_string(), Variant(Box::new(vec![i as u8; 8])));
+        messages.push(properties_changed(
+            format!("{}/service0010/char0011", device_path(i)),
+            "org.bluez.GattCharacteristic1",
+            value,
+        ));
+    }
+    messages
+}
@@ -0,0 +1,69 @@
+//! Compares delivering the same D-Bus signals to many subscribers through a separate event stream
+//! for each, as with calling `BluetoothSession::events` once per subscriber, against a single
+//! shared stream from `BluetoothSession::broadcast_events`.
+//!
+//! Run with `cargo bench -p bluez-async --features bench --bench event_fanout`.
+
+mod common;
+
+use bluez_async::bench::{broadcast_events_from_messages, events_from_messages};
+use bluez_async::EventFilter;
+use common::messages;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use futures::future::join_all;
+use futures::stream::{self, StreamExt};
+use tokio::runtime::Runtime;
+
+fn fanout(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let mut group = c.benchmark_group("event_fanout");
+    group.throughput(Throughput::Elements(messages().len() as u64));
+    for &subscribers in &[1, 10, 100] {
+        // Each event stream gets its own copy of every message from the D-Bus connection, and
+        // parses and filters it separately.
+        group.bench_with_input(
+            BenchmarkId::new("events", subscribers),
+            &subscribers,
+            |b, &subscribers| {
+                b.iter_batched(
+                    || (0..subscribers).map(|_| messages()).collect::<Vec<_>>(),
+                    |copies| {
+                        runtime.block_on(join_all(copies.into_iter().map(|messages| {
+                            events_from_messages(EventFilter::new(), stream::iter(messages)).count()
+                        })))
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("broadcast_events", subscribers),
+            &subscribers,
+            |b, &subscribers| {
+                b.iter_batched(
+                    messages,
+                    |messages| broadcast(&runtime, subscribers, messages),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Parse the messages once and share the events between the given number of subscribers, waiting
+/// for all of them to receive every event.
+fn broadcast(runtime: &Runtime, subscribers: usize, messages: Vec<dbus::Message>) -> Vec<usize> {
+    runtime.block_on(async {
+        let broadcaster =
+            broadcast_events_from_messages(EventFilter::new(), stream::iter(messages));
+        // Subscribe before yielding to the runtime, so that no subscriber misses any events.
+        let streams: Vec<_> = (0..subscribers).map(|_| broadcaster.subscribe()).collect();
+        join_all(streams.into_iter().map(StreamExt::count)).await
+    })
+}
+
+criterion_group!(benches, fanout);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+//! Benchmarks for parsing D-Bus signals into Bluetooth events, and dispatching them through event
+//! streams with various filters.
+//!
+//! Run with `cargo bench -p bluez-async --features bench --bench events`.
+
+mod common;
+
+use bluez_async::bench::{events_from_messages, message_to_events};
+use bluez_async::{uuid_from_u16, DeviceId, EventFilter};
+use common::messages;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("events");
+    group.throughput(Throughput::Elements(messages().len() as u64));
+    group.bench_function("parse", |b| {
+        b.iter_batched(
+            messages,
+            |messages| {
+                messages
+                    .into_iter()
+                    .map(message_to_events)
+                    .collect::<Vec<_>>()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn dispatch(c: &mut Criterion) {
+    let device: DeviceId = "hci0/dev_00_11_22_33_44_00".parse().unwrap();
+    let filters = [
+        ("all", EventFilter::new()),
+        ("device", EventFilter::new().device(&device)),
+        (
+            "service_uuid",
+            EventFilter::new().service_uuid(uuid_from_u16(0x1801)),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("events/dispatch");
+    group.throughput(Throughput::Elements(messages().len() as u64));
+    for (name, filter) in &filters {
+        group.bench_function(*name, |b| {
+            b.iter_batched(
+                messages,
+                |messages| {
+                    block_on(events_from_messages(filter.clone(), stream::iter(messages)).count())
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse, dispatch);
+criterion_main!(benches);
@@ -0,0 +1,164 @@
+//! Benchmarks for parsing device and characteristic information, as done by `get_devices`,
+//! `get_devices_on_adapter` and `get_characteristic_by_uuid`, for large numbers of devices and
+//! characteristics.
+//!
+//! Run with `cargo bench -p bluez-async --features bench --bench lookup`.
+
+use bluez_async::bench::{characteristic_by_uuid, devices_from_managed_objects};
+use bluez_async::{uuid_from_u16, AdapterId, ServiceId};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use dbus::arg::{PropMap, RefArg, Variant};
+use dbus::Path;
+use std::collections::HashMap;
+use std::hint::black_box;
+
+/// Build the result of a `GetManagedObjects` call with the given number of devices, spread
+/// evenly over two adapters.
+fn managed_objects(devices: usize) -> HashMap<Path<'static>, HashMap<String, PropMap>> {
+    let mut tree = HashMap::new();
+    for i in 0..devices {
+        let address = format!("00:11:22:33:{:02X}:{:02X}", i / 256, i % 256);
+        let object_path = format!("/org/bluez/hci{}/dev_{}", i % 2, address.replace(':', "_"));
+        let mut properties: PropMap = HashMap::new();
+        properties.insert("Address".to_string(), Variant(Box::new(address)));
+        properties.insert(
+            "AddressType".to_string(),
+            Variant(Box::new("random".to_string())),
+        );
+        properties.insert(
+            "Name".to_string(),
+            Variant(Box::new(format!("Device {}", i))),
+        );
+        properties.insert("RSSI".to_string(), Variant(Box::new(-70i16)));
+        for property in &[
+            "Paired",
+            "Connected",
+            "ServicesResolved",
+            "Bonded",
+            "Trusted",
+            "Blocked",
+            "LegacyPairing",
+        ] {
+            properties.insert(property.to_string(), Variant(Box::new(false)));
+        }
+        let services: Vec<String> = (0..4)
+            .map(|j| uuid_from_u16(0x1800 + j).to_string())
+            .collect();
+        properties.insert("UUIDs".to_string(), Variant(Box::new(services)));
+        let manufacturer_data: HashMap<u16, Variant<Box<dyn RefArg>>> = vec![(
+            i as u16,
+            Variant(Box::new(vec![i as u8; 24]) as Box<dyn RefArg>),
+        )]
+        .into_iter()
+        .collect();
+        properties.insert(
+            "ManufacturerData".to_string(),
+            Variant(Box::new(manufacturer_data)),
+        );
+        let service_data: PropMap = (0..2)
+            .map(|j| {
+                (
+                    uuid_from_u16(0x1800 + j).to_string(),
+                    Variant(Box::new(vec![i as u8; 20]) as Box<dyn RefArg>),
+                )
+            })
+            .collect();
+        properties.insert("ServiceData".to_string(), Variant(Box::new(service_data)));
+
+        let mut interfaces = HashMap::new();
+        interfaces.insert("org.bluez.Device1".to_string(), properties);
+        tree.insert(Path::from(object_path), interfaces);
+    }
+    tree
+}
+
+fn get_devices(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_devices");
+    let adapter: AdapterId = "hci1".parse().unwrap();
+    for &devices in &[100, 1000] {
+        group.bench_with_input(BenchmarkId::new("all", devices), &devices, |b, &devices| {
+            b.iter_batched(
+                || managed_objects(devices),
+                |tree| devices_from_managed_objects(tree, None),
+                BatchSize::LargeInput,
+            )
+        });
+        // How get_devices_on_adapter used to work, parsing every device and then filtering.
+        group.bench_with_input(
+            BenchmarkId::new("on_adapter_filter_after_parsing", devices),
+            &devices,
+            |b, &devices| {
+                b.iter_batched(
+                    || managed_objects(devices),
+                    |tree| {
+                        devices_from_managed_objects(tree, None)
+                            .into_iter()
+                            .filter(|device| device.id.adapter() == adapter)
+                            .collect::<Vec<_>>()
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("on_adapter", devices),
+            &devices,
+            |b, &devices| {
+                b.iter_batched(
+                    || managed_objects(devices),
+                    |tree| devices_from_managed_objects(tree, Some(&adapter)),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn get_characteristic_by_uuid(c: &mut Criterion) {
+    const CHARACTERISTICS: u16 = 20;
+    let service_path = "/org/bluez/hci0/dev_00_11_22_33_44_55/service0010";
+    let service: ServiceId = "hci0/dev_00_11_22_33_44_55/service0010".parse().unwrap();
+
+    let mut introspection_xml = r#"<node>
+        <interface name="org.bluez.GattService1">
+            <property name="UUID" type="s" access="read"></property>
+            <property name="Device" type="o" access="read"></property>
+            <property name="Primary" type="b" access="read"></property>
+        </interface>"#
+        .to_string();
+    let mut characteristic_properties = HashMap::new();
+    for i in 0..CHARACTERISTICS {
+        let name = format!("char{:04x}", 0x11 + i * 3);
+        introspection_xml.push_str(&format!(r#"<node name="{}"/>"#, name));
+        let mut properties: PropMap = HashMap::new();
+        properties.insert(
+            "UUID".to_string(),
+            Variant(Box::new(uuid_from_u16(0x2a00 + i).to_string())),
+        );
+        properties.insert(
+            "Flags".to_string(),
+            Variant(Box::new(vec!["read".to_string(), "notify".to_string()])),
+        );
+        characteristic_properties
+            .insert(Path::from(format!("{}/{}", service_path, name)), properties);
+    }
+    introspection_xml.push_str("</node>");
+
+    // Look up the last characteristic, so all of them have to be parsed.
+    let uuid = uuid_from_u16(0x2a00 + CHARACTERISTICS - 1);
+    c.bench_function("get_characteristic_by_uuid", |b| {
+        b.iter(|| {
+            characteristic_by_uuid(
+                &service,
+                black_box(&introspection_xml),
+                &characteristic_properties,
+                uuid,
+            )
+            .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, get_devices, get_characteristic_by_uuid);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! Example to register a pairing agent, and ask the user on the terminal whether to accept each
+//! authorization request from BlueZ.
+
+use bluez_async::{AgentCapability, AgentRequest, BluetoothSession};
+use futures::stream::StreamExt;
+use std::io::{self, Write};
+use tokio::io::{AsyncBufReadExt, BufReader, Stdin};
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Report> {
+    pretty_env_logger::init();
+
+    let (_, session) = BluetoothSession::new().await?;
+    let mut agent = session
+        .register_agent(AgentCapability::DisplayYesNo, true)
+        .await?;
+    println!("Registered agent at {}", agent.object_path());
+
+    // Read from stdin asynchronously, so as not to block the runtime while BlueZ is waiting for a
+    // reply to the agent method call.
+    let mut stdin = BufReader::new(tokio::io::stdin());
+
+    while let Some(request) = agent.next().await {
+        let question = match request.request() {
+            AgentRequest::AuthorizeService { device, service } => {
+                format!("Allow {} to use service {}?", device, service)
+            }
+            AgentRequest::RequestAuthorization { device } => format!("Pair with {}?", device),
+            AgentRequest::RequestConfirmation { device, passkey } => {
+                format!("Does {} show passkey {:06}?", device, passkey)
+            }
+            other => format!("{:?}?", other),
+        };
+        if ask(&mut stdin, &question).await? {
+            request.accept();
+        } else {
+            request.reject();
+        }
+    }
+
+    Ok(())
+}
+
+/// Ask the user a yes/no question on the terminal.
+async fn ask(stdin: &mut BufReader<Stdin>, question: &str) -> Result<bool, io::Error> {
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    stdin.read_line(&mut answer).await?;
+    Ok(answer.trim().eq_ignore_ascii_case("y"))
+}
@@ -68,22 +68,20 @@ async fn main() -> Result<(), eyre::Report> {
         .await?;
 
     while let Some(event) = events.next().await {
-        match event {
-            BluetoothEvent::Device {
-                id,
-                event: DeviceEvent::ManufacturerData { manufacturer_data },
-            } => {
-                if let Some(data) = get_ruuvi_data(&manufacturer_data) {
-                    let t = temperature(data);
-                    let h = humidity(data);
-                    let p = pressure(data);
-                    println!(
-                        "RuuviTag {} measured: t = {:6.2} °C, h = {:6.2} %, p = {:6} Pa",
-                        id, t, h, p
-                    );
-                }
+        if let BluetoothEvent::Device {
+            id,
+            event: DeviceEvent::ManufacturerData { manufacturer_data },
+        } = event
+        {
+            if let Some(data) = get_ruuvi_data(&manufacturer_data) {
+                let t = temperature(data);
+                let h = humidity(data);
+                let p = pressure(data);
+                println!(
+                    "RuuviTag {} measured: t = {:6.2} °C, h = {:6.2} %, p = {:6} Pa",
+                    id, t, h, p
+                );
             }
-            _ => {}
         }
     }
 
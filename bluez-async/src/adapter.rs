@@ -1,13 +1,19 @@
 use bluez_generated::OrgBluezAdapter1Properties;
 use dbus::Path;
 use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use uuid::Uuid;
 
-use crate::Modalias;
-use crate::{AddressType, BluetoothError, MacAddress};
+use crate::objectpath::{is_adapter_path, path_from_display};
+use crate::serde_path::SerializedId;
+use crate::{convert_uuids, AddressType, BluetoothError, MacAddress};
+use crate::{Modalias, ParseIdError};
 
 /// Opaque identifier for a Bluetooth adapter on the system.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "SerializedId")]
 pub struct AdapterId {
     #[serde(with = "crate::serde_path")]
     pub(crate) object_path: Path<'static>,
@@ -19,6 +25,11 @@ impl AdapterId {
             object_path: object_path.to_owned().into(),
         }
     }
+
+    /// Get the D-Bus object path of the adapter, e.g. `"/org/bluez/hci0"`.
+    pub fn object_path(&self) -> &str {
+        &self.object_path
+    }
 }
 
 impl From<AdapterId> for Path<'static> {
@@ -27,6 +38,39 @@ impl From<AdapterId> for Path<'static> {
     }
 }
 
+impl TryFrom<Path<'static>> for AdapterId {
+    type Error = ParseIdError;
+
+    fn try_from(object_path: Path<'static>) -> Result<Self, Self::Error> {
+        if is_adapter_path(&object_path) {
+            Ok(Self { object_path })
+        } else {
+            Err(ParseIdError::new("adapter", &object_path))
+        }
+    }
+}
+
+impl TryFrom<SerializedId> for AdapterId {
+    type Error = ParseIdError;
+
+    fn try_from(id: SerializedId) -> Result<Self, Self::Error> {
+        id.try_into_id()
+    }
+}
+
+impl FromStr for AdapterId {
+    type Err = ParseIdError;
+
+    /// Parse an adapter ID in the format produced by its `Display` implementation, e.g.
+    /// `"hci0"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::new(path_from_display(s))
+            .ok()
+            .and_then(|object_path| object_path.try_into().ok())
+            .ok_or_else(|| ParseIdError::new("adapter", s))
+    }
+}
+
 impl Display for AdapterId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -59,6 +103,12 @@ pub struct AdapterInfo {
     pub powered: bool,
     /// Whether the adapter is currently discovering devices.
     pub discovering: bool,
+    /// The LE roles which the adapter supports, such as `"central"`, `"peripheral"` or
+    /// `"central-peripheral"`. This is empty if BlueZ doesn't report them.
+    pub roles: Vec<String>,
+    /// The UUIDs of the BlueZ experimental features which are currently enabled for the adapter,
+    /// such as the advertisement monitor. This is empty if none are enabled.
+    pub experimental_features: Vec<Uuid>,
 }
 
 impl AdapterInfo {
@@ -98,6 +148,12 @@ impl AdapterInfo {
             discovering: adapter_properties
                 .discovering()
                 .ok_or(BluetoothError::RequiredPropertyMissing("Discovering"))?,
+            roles: adapter_properties.roles().cloned().unwrap_or_default(),
+            experimental_features: adapter_properties
+                .experimental_features()
+                .map(Vec::as_slice)
+                .map(convert_uuids)
+                .unwrap_or_default(),
         })
     }
 }
@@ -149,14 +205,95 @@ mod tests {
                     device_id: 0x90ab
                 },
                 powered: false,
-                discovering: false
+                discovering: false,
+                roles: vec![],
+                experimental_features: vec![],
             }
         )
     }
 
+    #[test]
+    fn adapter_info_roles_experimental_features() {
+        let id = AdapterId::new("/org/bluez/hci0");
+        let mut adapter_properties: PropMap = HashMap::new();
+        adapter_properties.insert(
+            "Address".to_string(),
+            Variant(Box::new("00:11:22:33:44:55".to_string())),
+        );
+        adapter_properties.insert(
+            "AddressType".to_string(),
+            Variant(Box::new("public".to_string())),
+        );
+        adapter_properties.insert("Name".to_string(), Variant(Box::new("name".to_string())));
+        adapter_properties.insert("Alias".to_string(), Variant(Box::new("alias".to_string())));
+        adapter_properties.insert(
+            "Modalias".to_string(),
+            Variant(Box::new("usb:v1234p5678d90AB".to_string())),
+        );
+        adapter_properties.insert("Powered".to_string(), Variant(Box::new(true)));
+        adapter_properties.insert("Discovering".to_string(), Variant(Box::new(false)));
+        adapter_properties.insert(
+            "Roles".to_string(),
+            Variant(Box::new(vec![
+                "central".to_string(),
+                "peripheral".to_string(),
+            ])),
+        );
+        adapter_properties.insert(
+            "ExperimentalFeatures".to_string(),
+            Variant(Box::new(vec![
+                "15c0a148-c273-11ea-b3de-0242ac130004".to_string(),
+                "invalid".to_string(),
+            ])),
+        );
+
+        let adapter =
+            AdapterInfo::from_properties(id, OrgBluezAdapter1Properties(&adapter_properties))
+                .unwrap();
+        assert_eq!(adapter.roles, vec!["central", "peripheral"]);
+        assert_eq!(
+            adapter.experimental_features,
+            vec![Uuid::parse_str("15c0a148-c273-11ea-b3de-0242ac130004").unwrap()]
+        );
+    }
+
     #[test]
     fn to_string() {
         let adapter_id = AdapterId::new("/org/bluez/hci0");
         assert_eq!(adapter_id.to_string(), "hci0");
     }
+
+    #[test]
+    fn from_str() {
+        let adapter_id = AdapterId::new("/org/bluez/hci0");
+        assert_eq!("hci0".parse(), Ok(adapter_id.clone()));
+        assert_eq!(adapter_id.to_string().parse(), Ok(adapter_id));
+        assert!("hci0/dev_11_22_33_44_55_66".parse::<AdapterId>().is_err());
+        assert!("".parse::<AdapterId>().is_err());
+    }
+
+    #[test]
+    fn try_from_path() {
+        let path = Path::from("/org/bluez/hci0");
+        assert_eq!(
+            AdapterId::try_from(path.clone()),
+            Ok(AdapterId::new("/org/bluez/hci0"))
+        );
+        assert_eq!(
+            AdapterId::try_from(path).unwrap().object_path(),
+            "/org/bluez/hci0"
+        );
+        assert!(AdapterId::try_from(Path::from("/org/bluez")).is_err());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let adapter_id = AdapterId::new("/org/bluez/hci0");
+        let json = serde_json::to_string(&adapter_id).unwrap();
+        assert_eq!(
+            serde_json::from_str::<AdapterId>(&json).unwrap(),
+            adapter_id
+        );
+        assert!(serde_json::from_str::<AdapterId>(r#"{"object_path":"/org/bluez"}"#).is_err());
+    }
 }
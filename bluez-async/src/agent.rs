@@ -0,0 +1,753 @@
+use bluez_generated::OrgBluezAgentManager1;
+use dbus::channel::{MatchingReceiver, Sender, Token};
+use dbus::message::MatchRule;
+use dbus::nonblock::{Proxy, SyncConnection};
+use dbus::{Message, Path};
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures::{Stream, StreamExt};
+use std::ffi::CString;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll};
+use tokio::runtime::Handle;
+use uuid::Uuid;
+
+use crate::{BluetoothError, DeviceId, DBUS_METHOD_CALL_TIMEOUT};
+
+const AGENT1_INTERFACE: &str = "org.bluez.Agent1";
+const ERROR_REJECTED: &str = "org.bluez.Error.Rejected";
+const ERROR_INVALID_ARGS: &str = "org.freedesktop.DBus.Error.InvalidArgs";
+
+/// The largest passkey which can be used for pairing, as passkeys are 6 decimal digits.
+const MAX_PASSKEY: u32 = 999999;
+/// The maximum length in bytes of a PIN code for legacy pairing.
+const MAX_PIN_CODE_LENGTH: usize = 16;
+
+/// Used to give each agent registered by this process a unique object path.
+static NEXT_AGENT_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+/// The input and output capabilities of an agent, which determine which pairing methods BlueZ
+/// will use with it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AgentCapability {
+    /// The agent can display a passkey, but not take any input.
+    DisplayOnly,
+    /// The agent can display a passkey and ask the user to confirm it.
+    DisplayYesNo,
+    /// The agent can take passkey input, but not display anything.
+    KeyboardOnly,
+    /// The agent has no input or output, so only 'just works' pairing is possible.
+    NoInputNoOutput,
+    /// The agent can both display a passkey and take passkey input.
+    KeyboardDisplay,
+}
+
+impl AgentCapability {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::DisplayOnly => "DisplayOnly",
+            Self::DisplayYesNo => "DisplayYesNo",
+            Self::KeyboardOnly => "KeyboardOnly",
+            Self::NoInputNoOutput => "NoInputNoOutput",
+            Self::KeyboardDisplay => "KeyboardDisplay",
+        }
+    }
+}
+
+impl Display for AgentCapability {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Details of a request from BlueZ for an agent to accept or reject some operation, or to provide
+/// a passkey or PIN code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AgentRequest {
+    /// A remote device wants to connect to the given service (i.e. profile) on this host.
+    AuthorizeService {
+        /// The device which is trying to connect.
+        device: DeviceId,
+        /// The UUID of the service or profile it wants to use.
+        service: Uuid,
+    },
+    /// A remote device is trying to pair using a method which would otherwise not involve the
+    /// user, such as 'just works' pairing.
+    RequestAuthorization {
+        /// The device which is trying to pair.
+        device: DeviceId,
+    },
+    /// The user should confirm that the given passkey is displayed on the remote device.
+    RequestConfirmation {
+        /// The device which is pairing.
+        device: DeviceId,
+        /// The 6 digit passkey, which should be displayed with leading zeros.
+        passkey: u32,
+    },
+    /// The user should enter the passkey displayed on the remote device, which should then be
+    /// given with [`provide_passkey`](AuthorizationRequest::provide_passkey).
+    RequestPasskey {
+        /// The device which is pairing.
+        device: DeviceId,
+    },
+    /// The user should enter the PIN code for the remote device, for legacy pairing, which should
+    /// then be given with [`provide_pin_code`](AuthorizationRequest::provide_pin_code).
+    RequestPinCode {
+        /// The device which is pairing.
+        device: DeviceId,
+    },
+    /// The given passkey should be displayed to the user, for them to enter on the remote device.
+    ///
+    /// This may be sent again each time the user enters a digit. BlueZ doesn't wait for a reply,
+    /// so it makes no difference whether it is accepted or rejected.
+    DisplayPasskey {
+        /// The device which is pairing.
+        device: DeviceId,
+        /// The 6 digit passkey, which should be displayed with leading zeros.
+        passkey: u32,
+        /// The number of digits which the user has entered on the remote device so far.
+        entered: u16,
+    },
+    /// The given PIN code should be displayed to the user, for them to enter on the remote device.
+    /// Rejecting this cancels the pairing.
+    DisplayPinCode {
+        /// The device which is pairing.
+        device: DeviceId,
+        /// The PIN code to display.
+        pin_code: String,
+    },
+}
+
+/// A step of [`pair_and_trust`](crate::BluetoothSession::pair_and_trust) which has been
+/// completed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PairingProgress {
+    /// A temporary agent has been registered to handle the pairing.
+    AgentRegistered,
+    /// The device wasn't already paired, so pairing has started.
+    PairingStarted,
+    /// The device is paired, either because pairing succeeded or because it was already paired.
+    Paired,
+    /// The device has been marked as trusted. This is the last step if the pairing isn't bonded.
+    Trusted,
+    /// The pairing has been bonded, so the keys are stored for future connections. This is the
+    /// last step if it happens.
+    Bonded,
+}
+
+/// The method call for a request, until it has been replied to or cancelled.
+type PendingReply = Mutex<Option<Message>>;
+
+/// How the application has responded to a request.
+#[derive(Debug)]
+enum Response {
+    Accept,
+    Reject,
+    Passkey(u32),
+    PinCode(String),
+}
+
+/// A request from BlueZ which is waiting for the application to respond to it.
+///
+/// Most requests should be accepted or rejected, but [`AgentRequest::RequestPasskey`] and
+/// [`AgentRequest::RequestPinCode`] need a passkey or PIN code to be provided instead. If this is
+/// dropped without responding then the request will be rejected.
+pub struct AuthorizationRequest {
+    request: AgentRequest,
+    method_call: Arc<PendingReply>,
+    connection: Arc<SyncConnection>,
+}
+
+impl AuthorizationRequest {
+    /// Get the details of what is being requested.
+    pub fn request(&self) -> &AgentRequest {
+        &self.request
+    }
+
+    /// Allow the operation to go ahead.
+    ///
+    /// A passkey or PIN code request can't just be accepted, so it will be rejected instead.
+    pub fn accept(mut self) {
+        self.respond(Response::Accept);
+    }
+
+    /// Refuse the operation.
+    pub fn reject(mut self) {
+        self.respond(Response::Reject);
+    }
+
+    /// Reply to an [`AgentRequest::RequestPasskey`] with the passkey which the user entered, which
+    /// must be at most 999999.
+    ///
+    /// If this is a different kind of request or the passkey is out of range then the request will
+    /// be rejected instead.
+    pub fn provide_passkey(mut self, passkey: u32) {
+        self.respond(Response::Passkey(passkey));
+    }
+
+    /// Reply to an [`AgentRequest::RequestPinCode`] with the PIN code which the user entered,
+    /// which must be between 1 and 16 bytes long.
+    ///
+    /// If this is a different kind of request or the PIN code isn't valid then the request will be
+    /// rejected instead.
+    pub fn provide_pin_code(mut self, pin_code: &str) {
+        self.respond(Response::PinCode(pin_code.to_owned()));
+    }
+
+    /// Check whether BlueZ has cancelled the request, such as because the pairing timed out or the
+    /// remote device gave up. Responding to a cancelled request has no effect.
+    pub fn is_cancelled(&self) -> bool {
+        self.method_call.lock().unwrap().is_none()
+    }
+
+    fn respond(&mut self, response: Response) {
+        let method_call = self.method_call.lock().unwrap().take();
+        if let Some(method_call) = method_call {
+            let reply = reply(&self.request, &method_call, response);
+            if self.connection.send(reply).is_err() {
+                log::warn!("Failed to send reply to {:?}", self.request);
+            }
+        }
+    }
+}
+
+impl Debug for AuthorizationRequest {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AuthorizationRequest")
+            .field("request", &self.request)
+            .finish()
+    }
+}
+
+impl Drop for AuthorizationRequest {
+    fn drop(&mut self) {
+        self.respond(Response::Reject);
+    }
+}
+
+/// An agent registered with BlueZ, which receives requests to authorize pairing and incoming
+/// service connections.
+///
+/// This is a stream of [`AuthorizationRequest`]s, each of which should be responded to by the
+/// application. The stream ends if BlueZ releases the agent. The agent is unregistered when it is
+/// dropped.
+pub struct Agent {
+    object_path: Path<'static>,
+    token: Token,
+    requests: UnboundedReceiver<AuthorizationRequest>,
+    connection: Arc<SyncConnection>,
+}
+
+impl Agent {
+    pub(crate) async fn register(
+        connection: Arc<SyncConnection>,
+        capability: AgentCapability,
+        default: bool,
+    ) -> Result<Self, BluetoothError> {
+        let index = NEXT_AGENT_INDEX.fetch_add(1, Ordering::Relaxed);
+        let object_path: Path<'static> =
+            format!("/org/bluez_async/agent{}_{}", std::process::id(), index).into();
+        let (sender, requests) = mpsc::unbounded();
+        let match_rule = MatchRule::new_method_call()
+            .with_path(object_path.clone())
+            .with_interface(AGENT1_INTERFACE);
+        let weak_connection = Arc::downgrade(&connection);
+        let mut outstanding = vec![];
+        let token = connection.start_receive(
+            match_rule,
+            Box::new(move |message, _| {
+                handle_method_call(message, &weak_connection, &sender, &mut outstanding)
+            }),
+        );
+
+        let agent = Agent {
+            object_path,
+            token,
+            requests,
+            connection,
+        };
+        let agent_manager = agent.agent_manager();
+        agent_manager
+            .register_agent(agent.object_path.clone(), capability.as_str())
+            .await?;
+        if default {
+            agent_manager
+                .request_default_agent(agent.object_path.clone())
+                .await?;
+        }
+        Ok(agent)
+    }
+
+    /// Get the D-Bus object path at which the agent is exported.
+    pub fn object_path(&self) -> &str {
+        &self.object_path
+    }
+
+    fn agent_manager(&self) -> impl OrgBluezAgentManager1 {
+        Proxy::new(
+            "org.bluez",
+            "/org/bluez",
+            DBUS_METHOD_CALL_TIMEOUT,
+            self.connection.clone(),
+        )
+    }
+}
+
+impl Debug for Agent {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Agent")
+            .field("object_path", &self.object_path)
+            .finish()
+    }
+}
+
+impl Stream for Agent {
+    type Item = AuthorizationRequest;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.requests).poll_next(cx)
+    }
+}
+
+impl Drop for Agent {
+    fn drop(&mut self) {
+        self.connection.stop_receive(self.token);
+        let object_path = self.object_path.clone();
+        let agent_manager = self.agent_manager();
+        match Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    // This fails if the registration never succeeded or BlueZ has already released
+                    // the agent, which is fine.
+                    if let Err(e) = agent_manager.unregister_agent(object_path.clone()).await {
+                        log::debug!("Failed to unregister agent {}: {}", object_path, e);
+                    }
+                });
+            }
+            Err(_) => log::warn!(
+                "No tokio runtime available to unregister agent {}",
+                object_path
+            ),
+        }
+    }
+}
+
+/// Accept 'just works' pairing requests from the given device and reject all other requests to
+/// the agent, until the agent stops receiving requests.
+pub(crate) async fn authorize_pairing(agent: &mut Agent, device: &DeviceId) {
+    while let Some(request) = agent.next().await {
+        match request.request() {
+            AgentRequest::RequestAuthorization { device: requester } if requester == device => {
+                request.accept()
+            }
+            _ => {
+                log::info!("Rejecting {:?} while pairing {}", request.request(), device);
+                request.reject()
+            }
+        }
+    }
+}
+
+/// What to do with a method call to an agent.
+#[derive(Debug)]
+enum Dispatch {
+    /// Pass the request on to the application to decide.
+    Request(AgentRequest),
+    /// Send the given reply straight away.
+    Reply(Message),
+    /// BlueZ has given up on the outstanding requests, so they should no longer be replied to.
+    Cancel,
+    /// BlueZ has unregistered the agent, so no more requests will come.
+    Release,
+}
+
+/// Handle a method call to an agent object, either replying to it directly or passing it on to be
+/// responded to by the application. `outstanding` keeps track of the requests which have been
+/// passed on, so that they can be cancelled. Returns false once the agent has been dropped or
+/// released.
+fn handle_method_call(
+    message: Message,
+    connection: &Weak<SyncConnection>,
+    sender: &UnboundedSender<AuthorizationRequest>,
+    outstanding: &mut Vec<Weak<PendingReply>>,
+) -> bool {
+    let connection = match connection.upgrade() {
+        Some(connection) => connection,
+        None => return false,
+    };
+    let (reply, keep_receiving) = match dispatch(&message) {
+        Dispatch::Request(request) => {
+            let method_call = Arc::new(Mutex::new(Some(message)));
+            outstanding.retain(|pending| pending.strong_count() > 0);
+            outstanding.push(Arc::downgrade(&method_call));
+            let request = AuthorizationRequest {
+                request,
+                method_call,
+                connection,
+            };
+            // If the agent has been dropped then the request will be rejected when it is dropped.
+            return sender.unbounded_send(request).is_ok();
+        }
+        Dispatch::Reply(reply) => (reply, true),
+        Dispatch::Cancel => {
+            log::debug!("Agent requests cancelled");
+            cancel_outstanding(outstanding);
+            (message.method_return(), true)
+        }
+        Dispatch::Release => {
+            // Returning false drops the sender, which ends the agent's request stream.
+            log::info!("Agent released by BlueZ");
+            cancel_outstanding(outstanding);
+            (message.method_return(), false)
+        }
+    };
+    if connection.send(reply).is_err() {
+        log::warn!("Failed to send reply to agent method call {:?}", message);
+    }
+    keep_receiving
+}
+
+/// Mark all the requests which are still waiting for a response as cancelled, so that responding
+/// to them has no effect.
+fn cancel_outstanding(outstanding: &mut Vec<Weak<PendingReply>>) {
+    for pending in outstanding.drain(..) {
+        if let Some(pending) = pending.upgrade() {
+            pending.lock().unwrap().take();
+        }
+    }
+}
+
+/// Work out how to handle the given method call to an agent.
+fn dispatch(message: &Message) -> Dispatch {
+    let member = message.member();
+    let member = member.as_deref().unwrap_or_default();
+    let request = match member {
+        "AuthorizeService" => message
+            .read2::<Path, &str>()
+            .ok()
+            .and_then(|(device, uuid)| {
+                Some(AgentRequest::AuthorizeService {
+                    device: DeviceId {
+                        object_path: device.into_static(),
+                    },
+                    service: Uuid::parse_str(uuid).ok()?,
+                })
+            }),
+        "RequestAuthorization" => {
+            message
+                .read1::<Path>()
+                .ok()
+                .map(|device| AgentRequest::RequestAuthorization {
+                    device: DeviceId {
+                        object_path: device.into_static(),
+                    },
+                })
+        }
+        "RequestConfirmation" => message.read2::<Path, u32>().ok().map(|(device, passkey)| {
+            AgentRequest::RequestConfirmation {
+                device: DeviceId {
+                    object_path: device.into_static(),
+                },
+                passkey,
+            }
+        }),
+        "RequestPasskey" => {
+            message
+                .read1::<Path>()
+                .ok()
+                .map(|device| AgentRequest::RequestPasskey {
+                    device: DeviceId {
+                        object_path: device.into_static(),
+                    },
+                })
+        }
+        "RequestPinCode" => {
+            message
+                .read1::<Path>()
+                .ok()
+                .map(|device| AgentRequest::RequestPinCode {
+                    device: DeviceId {
+                        object_path: device.into_static(),
+                    },
+                })
+        }
+        "DisplayPasskey" => {
+            message
+                .read3::<Path, u32, u16>()
+                .ok()
+                .map(|(device, passkey, entered)| AgentRequest::DisplayPasskey {
+                    device: DeviceId {
+                        object_path: device.into_static(),
+                    },
+                    passkey,
+                    entered,
+                })
+        }
+        "DisplayPinCode" => message
+            .read2::<Path, &str>()
+            .ok()
+            .map(|(device, pin_code)| AgentRequest::DisplayPinCode {
+                device: DeviceId {
+                    object_path: device.into_static(),
+                },
+                pin_code: pin_code.to_owned(),
+            }),
+        "Cancel" => return Dispatch::Cancel,
+        "Release" => return Dispatch::Release,
+        _ => {
+            return Dispatch::Reply(error_reply(
+                message,
+                ERROR_REJECTED,
+                "Not supported by this agent",
+            ))
+        }
+    };
+    match request {
+        Some(request) => Dispatch::Request(request),
+        None => Dispatch::Reply(error_reply(
+            message,
+            ERROR_INVALID_ARGS,
+            "Invalid arguments",
+        )),
+    }
+}
+
+/// Build the reply to send for the given response to a request.
+fn reply(request: &AgentRequest, method_call: &Message, response: Response) -> Message {
+    let reply = match (request, response) {
+        (_, Response::Reject) => None,
+        (AgentRequest::RequestPasskey { .. }, Response::Passkey(passkey))
+            if passkey <= MAX_PASSKEY =>
+        {
+            Some(method_call.method_return().append1(passkey))
+        }
+        (AgentRequest::RequestPinCode { .. }, Response::PinCode(pin_code))
+            if is_valid_pin_code(&pin_code) =>
+        {
+            Some(method_call.method_return().append1(pin_code))
+        }
+        (AgentRequest::RequestPasskey { .. } | AgentRequest::RequestPinCode { .. }, response)
+        | (_, response @ (Response::Passkey(_) | Response::PinCode(_))) => {
+            log::warn!("Invalid response {:?} to {:?}", response, request);
+            None
+        }
+        (_, Response::Accept) => Some(method_call.method_return()),
+    };
+    reply.unwrap_or_else(|| error_reply(method_call, ERROR_REJECTED, "Rejected by application"))
+}
+
+/// Check whether the given PIN code is acceptable to BlueZ for legacy pairing.
+fn is_valid_pin_code(pin_code: &str) -> bool {
+    !pin_code.is_empty() && pin_code.len() <= MAX_PIN_CODE_LENGTH
+}
+
+fn error_reply(method_call: &Message, error_name: &'static str, error_message: &str) -> Message {
+    let error_message = CString::new(error_message).unwrap();
+    method_call.error(&error_name.into(), &error_message)
+}
+
+#[cfg(test)]
+mod tests {
+    use dbus::message::MessageType;
+
+    use super::*;
+    use crate::uuid_from_u16;
+
+    const DEVICE_PATH: &str = "/org/bluez/hci0/dev_11_22_33_44_55_66";
+
+    fn agent_method_call(member: &'static str) -> Message {
+        let mut message =
+            Message::new_method_call("com.example", "/agent", AGENT1_INTERFACE, member).unwrap();
+        message.set_serial(42);
+        message
+    }
+
+    #[test]
+    fn authorize_service() {
+        let uuid = uuid_from_u16(0x110b);
+        let message = agent_method_call("AuthorizeService")
+            .append2(Path::from(DEVICE_PATH), uuid.to_string());
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Request(AgentRequest::AuthorizeService { device, service })
+                if device == DeviceId::new(DEVICE_PATH) && service == uuid
+        ));
+    }
+
+    #[test]
+    fn request_authorization() {
+        let message = agent_method_call("RequestAuthorization").append1(Path::from(DEVICE_PATH));
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Request(AgentRequest::RequestAuthorization { device })
+                if device == DeviceId::new(DEVICE_PATH)
+        ));
+    }
+
+    #[test]
+    fn request_confirmation() {
+        let message =
+            agent_method_call("RequestConfirmation").append2(Path::from(DEVICE_PATH), 123456u32);
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Request(AgentRequest::RequestConfirmation { device, passkey: 123456 })
+                if device == DeviceId::new(DEVICE_PATH)
+        ));
+    }
+
+    #[test]
+    fn request_passkey() {
+        let message = agent_method_call("RequestPasskey").append1(Path::from(DEVICE_PATH));
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Request(AgentRequest::RequestPasskey { device })
+                if device == DeviceId::new(DEVICE_PATH)
+        ));
+    }
+
+    #[test]
+    fn request_pin_code() {
+        let message = agent_method_call("RequestPinCode").append1(Path::from(DEVICE_PATH));
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Request(AgentRequest::RequestPinCode { device })
+                if device == DeviceId::new(DEVICE_PATH)
+        ));
+    }
+
+    #[test]
+    fn display_passkey() {
+        let message =
+            agent_method_call("DisplayPasskey").append3(Path::from(DEVICE_PATH), 1234u32, 2u16);
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Request(AgentRequest::DisplayPasskey { device, passkey: 1234, entered: 2 })
+                if device == DeviceId::new(DEVICE_PATH)
+        ));
+    }
+
+    #[test]
+    fn display_pin_code() {
+        let message = agent_method_call("DisplayPinCode").append2(Path::from(DEVICE_PATH), "0000");
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Request(AgentRequest::DisplayPinCode { device, pin_code })
+                if device == DeviceId::new(DEVICE_PATH) && pin_code == "0000"
+        ));
+    }
+
+    #[test]
+    fn release_and_cancel() {
+        assert!(matches!(
+            dispatch(&agent_method_call("Release")),
+            Dispatch::Release
+        ));
+        assert!(matches!(
+            dispatch(&agent_method_call("Cancel")),
+            Dispatch::Cancel
+        ));
+    }
+
+    #[test]
+    fn unknown_method_rejected() {
+        let message = agent_method_call("Frobnicate");
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Reply(reply) if reply.msg_type() == MessageType::Error
+                && reply.get1::<&str>() == Some("Not supported by this agent")
+        ));
+    }
+
+    #[test]
+    fn reply_passkey() {
+        let request = AgentRequest::RequestPasskey {
+            device: DeviceId::new(DEVICE_PATH),
+        };
+        let method_call = agent_method_call("RequestPasskey");
+
+        let provided = reply(&request, &method_call, Response::Passkey(123456));
+        assert_eq!(provided.msg_type(), MessageType::MethodReturn);
+        assert_eq!(provided.get1::<u32>(), Some(123456));
+
+        let out_of_range = reply(&request, &method_call, Response::Passkey(1000000));
+        assert_eq!(out_of_range.msg_type(), MessageType::Error);
+        // A passkey request can't just be accepted.
+        let accepted = reply(&request, &method_call, Response::Accept);
+        assert_eq!(accepted.msg_type(), MessageType::Error);
+    }
+
+    #[test]
+    fn reply_pin_code() {
+        let request = AgentRequest::RequestPinCode {
+            device: DeviceId::new(DEVICE_PATH),
+        };
+        let method_call = agent_method_call("RequestPinCode");
+
+        let provided = reply(
+            &request,
+            &method_call,
+            Response::PinCode("0000".to_string()),
+        );
+        assert_eq!(provided.msg_type(), MessageType::MethodReturn);
+        assert_eq!(provided.get1::<&str>(), Some("0000"));
+
+        for invalid in ["", "01234567890123456"] {
+            let rejected = reply(
+                &request,
+                &method_call,
+                Response::PinCode(invalid.to_string()),
+            );
+            assert_eq!(rejected.msg_type(), MessageType::Error);
+        }
+        let passkey = reply(&request, &method_call, Response::Passkey(0));
+        assert_eq!(passkey.msg_type(), MessageType::Error);
+    }
+
+    #[test]
+    fn reply_accept_reject() {
+        let request = AgentRequest::RequestAuthorization {
+            device: DeviceId::new(DEVICE_PATH),
+        };
+        let method_call = agent_method_call("RequestAuthorization");
+        assert_eq!(
+            reply(&request, &method_call, Response::Accept).msg_type(),
+            MessageType::MethodReturn
+        );
+        assert_eq!(
+            reply(&request, &method_call, Response::Reject).msg_type(),
+            MessageType::Error
+        );
+        assert_eq!(
+            reply(&request, &method_call, Response::Passkey(0)).msg_type(),
+            MessageType::Error
+        );
+    }
+
+    #[test]
+    fn cancel_outstanding_requests() {
+        let first = Arc::new(Mutex::new(Some(agent_method_call("RequestPasskey"))));
+        let second = Arc::new(Mutex::new(Some(agent_method_call("RequestConfirmation"))));
+        let mut outstanding = vec![Arc::downgrade(&first), Arc::downgrade(&second)];
+        // A request which has already been dropped.
+        outstanding.push(Arc::downgrade(&Arc::new(Mutex::new(None))));
+
+        cancel_outstanding(&mut outstanding);
+
+        assert!(first.lock().unwrap().is_none());
+        assert!(second.lock().unwrap().is_none());
+        assert!(outstanding.is_empty());
+    }
+
+    #[test]
+    fn invalid_args() {
+        let message = agent_method_call("RequestConfirmation").append1(Path::from(DEVICE_PATH));
+        assert!(matches!(
+            dispatch(&message),
+            Dispatch::Reply(reply) if reply.msg_type() == MessageType::Error
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+use futures::stream::{self, StreamExt};
+use std::future::Future;
+
+/// Run a set of GATT operations (or any other futures) with at most `max_concurrent` of them in
+/// progress at once, and collect all of their results in the same order as the operations.
+///
+/// BlueZ returns an `InProgress` error if too many operations are made on the same device at once,
+/// so naively joining a large number of reads or writes is likely to fail. A `max_concurrent` of
+/// 0 is treated as 1.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bluez_async::{gatt_batch, BluetoothError, BluetoothSession, CharacteristicInfo};
+/// # async fn example(session: BluetoothSession, characteristics: Vec<CharacteristicInfo>)
+/// #     -> Result<(), BluetoothError> {
+/// let values = gatt_batch(
+///     characteristics
+///         .iter()
+///         .map(|characteristic| session.read_characteristic_value(&characteristic.id)),
+///     2,
+/// )
+/// .await;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn gatt_batch<I, F, T>(operations: I, max_concurrent: usize) -> Vec<T>
+where
+    I: IntoIterator<Item = F>,
+    F: Future<Output = T>,
+{
+    stream::iter(operations)
+        .buffered(max_concurrent.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::future;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::task::Poll;
+
+    use super::*;
+
+    #[test]
+    fn results_in_order() {
+        let results = block_on(gatt_batch((0..10).map(future::ready), 3));
+        assert_eq!(results, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn concurrency_limited() {
+        let in_progress = Rc::new(Cell::new(0));
+        let max_in_progress = Rc::new(Cell::new(0));
+        let operations = (0..10).map(|i| {
+            let in_progress = in_progress.clone();
+            let max_in_progress = max_in_progress.clone();
+            async move {
+                in_progress.set(in_progress.get() + 1);
+                max_in_progress.set(max_in_progress.get().max(in_progress.get()));
+                // Yield once so that other operations have a chance to start.
+                let mut yielded = false;
+                future::poll_fn(|cx| {
+                    if yielded {
+                        Poll::Ready(())
+                    } else {
+                        yielded = true;
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                })
+                .await;
+                in_progress.set(in_progress.get() - 1);
+                i
+            }
+        });
+        let results = block_on(gatt_batch(operations, 4));
+        assert_eq!(results, (0..10).collect::<Vec<_>>());
+        assert_eq!(max_in_progress.get(), 4);
+    }
+
+    #[test]
+    fn zero_concurrency() {
+        let results = block_on(gatt_batch(vec![future::ready(1)], 0));
+        assert_eq!(results, vec![1]);
+    }
+}
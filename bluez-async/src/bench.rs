@@ -0,0 +1,82 @@
+//! Entry points for the benchmarks in `benches/`, to measure the parsing and dispatch which
+//! `BluetoothSession` does without needing a running BlueZ daemon.
+//!
+//! This isn't part of the public API, and may change at any time.
+
+use bluez_generated::OrgBluezGattCharacteristic1Properties;
+use dbus::arg::PropMap;
+use dbus::{Message, Path};
+use futures::Stream;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::characteristic::{characteristic_ids, find_characteristic_by_uuid};
+use crate::device::DeviceFilter;
+use crate::introspect::Node;
+use crate::{
+    AdapterId, BluetoothError, BluetoothEvent, CharacteristicInfo, DeviceInfo, EventBroadcaster,
+    EventFilter, ServiceId,
+};
+
+/// Parse devices from the result of a `GetManagedObjects` call, as
+/// [`get_devices`](crate::BluetoothSession::get_devices) and
+/// [`get_devices_on_adapter`](crate::BluetoothSession::get_devices_on_adapter) do.
+pub fn devices_from_managed_objects(
+    tree: HashMap<Path<'static>, HashMap<String, PropMap>>,
+    adapter: Option<&AdapterId>,
+) -> Vec<DeviceInfo> {
+    crate::device::devices_from_managed_objects(
+        tree,
+        DeviceFilter {
+            adapter,
+            ..Default::default()
+        },
+    )
+}
+
+/// Find the characteristic with the given UUID on a service, as
+/// [`get_characteristic_by_uuid`](crate::BluetoothSession::get_characteristic_by_uuid) does, given
+/// the introspection XML of the service and the properties of each characteristic rather than
+/// fetching them over D-Bus.
+pub fn characteristic_by_uuid(
+    service: &ServiceId,
+    introspection_xml: &str,
+    characteristic_properties: &HashMap<Path<'static>, PropMap>,
+    uuid: Uuid,
+) -> Result<CharacteristicInfo, BluetoothError> {
+    let service_node: Node = serde_xml_rs::from_str(introspection_xml)?;
+    let mut characteristics = vec![];
+    for id in characteristic_ids(service, &service_node) {
+        let properties =
+            OrgBluezGattCharacteristic1Properties(&characteristic_properties[&id.object_path]);
+        characteristics.push(CharacteristicInfo::from_properties(id, properties)?);
+    }
+    find_characteristic_by_uuid(characteristics, uuid)
+}
+
+/// Parse the Bluetooth events from a D-Bus message, as event streams do for each message they
+/// receive.
+pub fn message_to_events(message: Message) -> Vec<BluetoothEvent> {
+    BluetoothEvent::message_to_events(message)
+}
+
+/// Parse and filter a stream of D-Bus messages, as the stream returned by
+/// [`events`](crate::BluetoothSession::events) does. If the filter has a service UUID then no
+/// devices are initially known to advertise it.
+pub fn events_from_messages(
+    filter: EventFilter,
+    messages: impl Stream<Item = Message>,
+) -> impl Stream<Item = BluetoothEvent> {
+    filter.filter_messages(messages, HashSet::new())
+}
+
+/// Parse and filter a stream of D-Bus messages and share the events between subscribers, as
+/// [`broadcast_events`](crate::BluetoothSession::broadcast_events) does.
+///
+/// This must be called from within a Tokio runtime.
+pub fn broadcast_events_from_messages(
+    filter: EventFilter,
+    messages: impl Stream<Item = Message> + Send + 'static,
+) -> EventBroadcaster {
+    EventBroadcaster::new(events_from_messages(filter, messages))
+}
@@ -0,0 +1,102 @@
+use futures::channel::mpsc::{self, UnboundedSender};
+use futures::{Stream, StreamExt};
+use std::fmt::{self, Debug, Formatter};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::BluetoothEvent;
+
+/// A single subscription to Bluetooth events which can be shared by many subscribers.
+///
+/// Each event is parsed once and sent to every subscriber as an `Arc<BluetoothEvent>`, rather
+/// than each subscriber having its own event stream and so its own copy of every event. This
+/// matters for applications with many subscribers to events with large payloads, such as
+/// manufacturer or service data.
+///
+/// Events are forwarded by a background task, which is stopped when the broadcaster is dropped.
+pub struct EventBroadcaster {
+    subscribers: Arc<Mutex<Vec<UnboundedSender<Arc<BluetoothEvent>>>>>,
+    task: JoinHandle<()>,
+}
+
+impl EventBroadcaster {
+    pub(crate) fn new(events: impl Stream<Item = BluetoothEvent> + Send + 'static) -> Self {
+        let subscribers: Arc<Mutex<Vec<UnboundedSender<Arc<BluetoothEvent>>>>> = Default::default();
+        let task_subscribers = subscribers.clone();
+        let task = tokio::spawn(async move {
+            futures::pin_mut!(events);
+            while let Some(event) = events.next().await {
+                let event = Arc::new(event);
+                // Drop any subscribers whose streams have been dropped.
+                task_subscribers
+                    .lock()
+                    .unwrap()
+                    .retain(|subscriber| subscriber.unbounded_send(event.clone()).is_ok());
+            }
+            // Close the subscriber streams.
+            task_subscribers.lock().unwrap().clear();
+        });
+        Self { subscribers, task }
+    }
+
+    /// Get a new stream of the events, starting from the next one received.
+    ///
+    /// The stream ends when the broadcaster is dropped, or if the underlying event stream ends.
+    pub fn subscribe(&self) -> impl Stream<Item = Arc<BluetoothEvent>> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.subscribers.lock().unwrap().push(sender);
+        receiver
+    }
+
+    /// Get the number of subscribers whose streams haven't been dropped yet.
+    pub fn subscriber_count(&self) -> usize {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|subscriber| !subscriber.is_closed());
+        subscribers.len()
+    }
+}
+
+impl Debug for EventBroadcaster {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("EventBroadcaster")
+            .field("subscribers", &self.subscribers.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl Drop for EventBroadcaster {
+    fn drop(&mut self) {
+        self.task.abort();
+        // Close the subscriber streams.
+        self.subscribers.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeviceEvent, DeviceId};
+
+    #[tokio::test]
+    async fn broadcast_to_subscribers() {
+        let (sender, receiver) = mpsc::unbounded();
+        let broadcaster = EventBroadcaster::new(receiver);
+        let first = broadcaster.subscribe();
+        let second = broadcaster.subscribe();
+        assert_eq!(broadcaster.subscriber_count(), 2);
+
+        let event = BluetoothEvent::Device {
+            id: DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66"),
+            event: DeviceEvent::Rssi { rssi: 42 },
+        };
+        sender.unbounded_send(event.clone()).unwrap();
+        drop(sender);
+
+        let first: Vec<_> = first.collect().await;
+        let second: Vec<_> = second.collect().await;
+        assert_eq!(first.len(), 1);
+        assert_eq!(*first[0], event);
+        // Both subscribers share the same event.
+        assert!(Arc::ptr_eq(&first[0], &second[0]));
+    }
+}
@@ -4,12 +4,17 @@ use dbus::Path;
 use serde::{Deserialize, Serialize};
 use std::convert::{TryFrom, TryInto};
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 use uuid::Uuid;
 
-use crate::{BluetoothError, ServiceId};
+use crate::introspect::Node;
+use crate::objectpath::{is_characteristic_path, path_from_display};
+use crate::serde_path::SerializedId;
+use crate::{AdapterId, BluetoothError, DeviceId, ParseIdError, ServiceId};
 
 /// Opaque identifier for a GATT characteristic on a Bluetooth device.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "SerializedId")]
 pub struct CharacteristicId {
     #[serde(with = "crate::serde_path")]
     pub(crate) object_path: Path<'static>,
@@ -30,6 +35,23 @@ impl CharacteristicId {
             .expect("CharacteristicId object_path must contain a slash.");
         ServiceId::new(&self.object_path[0..index])
     }
+
+    /// Get the ID of the device on which this characteristic was advertised.
+    pub fn device(&self) -> DeviceId {
+        self.service().device()
+    }
+
+    /// Get the ID of the Bluetooth adapter on which the device offering this characteristic was
+    /// discovered.
+    pub fn adapter(&self) -> AdapterId {
+        self.service().adapter()
+    }
+
+    /// Get the D-Bus object path of the characteristic, e.g.
+    /// `"/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0033"`.
+    pub fn object_path(&self) -> &str {
+        &self.object_path
+    }
 }
 
 impl From<CharacteristicId> for Path<'static> {
@@ -38,6 +60,39 @@ impl From<CharacteristicId> for Path<'static> {
     }
 }
 
+impl TryFrom<Path<'static>> for CharacteristicId {
+    type Error = ParseIdError;
+
+    fn try_from(object_path: Path<'static>) -> Result<Self, Self::Error> {
+        if is_characteristic_path(&object_path) {
+            Ok(Self { object_path })
+        } else {
+            Err(ParseIdError::new("characteristic", &object_path))
+        }
+    }
+}
+
+impl TryFrom<SerializedId> for CharacteristicId {
+    type Error = ParseIdError;
+
+    fn try_from(id: SerializedId) -> Result<Self, Self::Error> {
+        id.try_into_id()
+    }
+}
+
+impl FromStr for CharacteristicId {
+    type Err = ParseIdError;
+
+    /// Parse a characteristic ID in the format produced by its `Display` implementation, e.g.
+    /// `"hci0/dev_11_22_33_44_55_66/service0022/char0033"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::new(path_from_display(s))
+            .ok()
+            .and_then(|object_path| object_path.try_into().ok())
+            .ok_or_else(|| ParseIdError::new("characteristic", s))
+    }
+}
+
 impl Display for CharacteristicId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -93,6 +148,40 @@ impl CharacteristicInfo {
     }
 }
 
+/// Find the characteristic with the given UUID from a list of characteristics of a service.
+pub(crate) fn find_characteristic_by_uuid(
+    characteristics: Vec<CharacteristicInfo>,
+    uuid: Uuid,
+) -> Result<CharacteristicInfo, BluetoothError> {
+    characteristics
+        .into_iter()
+        .find(|characteristic_info| characteristic_info.uuid == uuid)
+        .ok_or(BluetoothError::UuidNotFound { uuid })
+}
+
+/// Get the IDs of the characteristics of the given service, from the result of introspecting it.
+pub(crate) fn characteristic_ids(
+    service: &ServiceId,
+    service_node: &Node,
+) -> Vec<CharacteristicId> {
+    service_node
+        .nodes
+        .iter()
+        .filter_map(|subnode| {
+            let subnode_name = subnode.name.as_ref()?;
+            // Characteristic paths are always of the form
+            // /org/bluez/{hci0,hci1,...}/dev_XX_XX_XX_XX_XX_XX/serviceXXXX/charYYYY
+            if subnode_name.starts_with("char") {
+                Some(CharacteristicId {
+                    object_path: format!("{}/{}", service.object_path, subnode_name).into(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 bitflags! {
     /// The set of flags (a.k.a. properties) of a characteristic, defining how the characteristic
     /// can be used.
@@ -198,6 +287,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn characteristic_ids_from_node() {
+        let service_id = ServiceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0022");
+        let service_node: Node = serde_xml_rs::from_str(
+            r#"<node>
+                <interface name="org.bluez.GattService1"></interface>
+                <node name="char0023"/>
+                <node name="char0026"/>
+            </node>"#,
+        )
+        .unwrap();
+        assert_eq!(
+            characteristic_ids(&service_id, &service_node),
+            vec![
+                CharacteristicId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0023"),
+                CharacteristicId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0026"),
+            ]
+        );
+    }
+
+    #[test]
+    fn characteristic_device_adapter() {
+        let characteristic_id =
+            CharacteristicId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0033");
+        assert_eq!(
+            characteristic_id.device(),
+            DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66")
+        );
+        assert_eq!(
+            characteristic_id.adapter(),
+            AdapterId::new("/org/bluez/hci0")
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let characteristic_id =
+            CharacteristicId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0033");
+        assert_eq!(characteristic_id.to_string().parse(), Ok(characteristic_id));
+        assert!("hci0/dev_11_22_33_44_55_66/service0022"
+            .parse::<CharacteristicId>()
+            .is_err());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let characteristic_id =
+            CharacteristicId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0033");
+        let json = serde_json::to_string(&characteristic_id).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CharacteristicId>(&json).unwrap(),
+            characteristic_id
+        );
+        assert!(serde_json::from_str::<CharacteristicId>(
+            r#"{"object_path":"/org/bluez/hci0/dev_11_22_33_44_55_66/service0022"}"#
+        )
+        .is_err());
+    }
+
     #[test]
     fn characteristic_info_minimal() {
         let id =
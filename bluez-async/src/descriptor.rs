@@ -1,12 +1,17 @@
 use dbus::Path;
 use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 use uuid::Uuid;
 
-use crate::CharacteristicId;
+use crate::objectpath::{is_descriptor_path, path_from_display};
+use crate::serde_path::SerializedId;
+use crate::{AdapterId, CharacteristicId, DeviceId, ParseIdError};
 
 /// Opaque identifier for a GATT characteristic descriptor on a Bluetooth device.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "SerializedId")]
 pub struct DescriptorId {
     #[serde(with = "crate::serde_path")]
     pub(crate) object_path: Path<'static>,
@@ -28,6 +33,23 @@ impl DescriptorId {
             .expect("DescriptorId object_path must contain a slash.");
         CharacteristicId::new(&self.object_path[0..index])
     }
+
+    /// Get the ID of the device on which this descriptor was advertised.
+    pub fn device(&self) -> DeviceId {
+        self.characteristic().device()
+    }
+
+    /// Get the ID of the Bluetooth adapter on which the device offering this descriptor was
+    /// discovered.
+    pub fn adapter(&self) -> AdapterId {
+        self.characteristic().adapter()
+    }
+
+    /// Get the D-Bus object path of the descriptor, e.g.
+    /// `"/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0033/desc0034"`.
+    pub fn object_path(&self) -> &str {
+        &self.object_path
+    }
 }
 
 impl From<DescriptorId> for Path<'static> {
@@ -36,6 +58,39 @@ impl From<DescriptorId> for Path<'static> {
     }
 }
 
+impl TryFrom<Path<'static>> for DescriptorId {
+    type Error = ParseIdError;
+
+    fn try_from(object_path: Path<'static>) -> Result<Self, Self::Error> {
+        if is_descriptor_path(&object_path) {
+            Ok(Self { object_path })
+        } else {
+            Err(ParseIdError::new("descriptor", &object_path))
+        }
+    }
+}
+
+impl TryFrom<SerializedId> for DescriptorId {
+    type Error = ParseIdError;
+
+    fn try_from(id: SerializedId) -> Result<Self, Self::Error> {
+        id.try_into_id()
+    }
+}
+
+impl FromStr for DescriptorId {
+    type Err = ParseIdError;
+
+    /// Parse a descriptor ID in the format produced by its `Display` implementation, e.g.
+    /// `"hci0/dev_11_22_33_44_55_66/service0022/char0033/desc0034"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::new(path_from_display(s))
+            .ok()
+            .and_then(|object_path| object_path.try_into().ok())
+            .ok_or_else(|| ParseIdError::new("descriptor", s))
+    }
+}
+
 impl Display for DescriptorId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -83,4 +138,27 @@ mod tests {
             "hci0/dev_11_22_33_44_55_66/service0022/char0033/desc0034"
         );
     }
+
+    #[test]
+    fn descriptor_device_adapter() {
+        let descriptor_id = DescriptorId::new(
+            "/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0033/desc0034",
+        );
+        assert_eq!(
+            descriptor_id.device(),
+            DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66")
+        );
+        assert_eq!(descriptor_id.adapter(), AdapterId::new("/org/bluez/hci0"));
+    }
+
+    #[test]
+    fn from_str() {
+        let descriptor_id = DescriptorId::new(
+            "/org/bluez/hci0/dev_11_22_33_44_55_66/service0022/char0033/desc0034",
+        );
+        assert_eq!(descriptor_id.to_string().parse(), Ok(descriptor_id));
+        assert!("hci0/dev_11_22_33_44_55_66/service0022/char0033"
+            .parse::<DescriptorId>()
+            .is_err());
+    }
 }
@@ -1,18 +1,23 @@
+use bitflags::bitflags;
 use bluez_generated::OrgBluezDevice1Properties;
 use dbus::arg::{cast, PropMap, RefArg, Variant};
 use dbus::Path;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 use uuid::Uuid;
 
-use crate::{AdapterId, BluetoothError, MacAddress};
+use crate::objectpath::{is_device_path, mac_address_from_node, path_from_display};
+use crate::serde_path::SerializedId;
+use crate::{AdapterId, BluetoothError, MacAddress, ParseIdError};
 
 /// Opaque identifier for a Bluetooth device which the system knows about. This includes a reference
 /// to which Bluetooth adapter it was discovered on, which means that any attempt to connect to it
 /// will also happen from that adapter (in case the system has more than one).
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "SerializedId")]
 pub struct DeviceId {
     #[serde(with = "crate::serde_path")]
     pub(crate) object_path: Path<'static>,
@@ -33,6 +38,21 @@ impl DeviceId {
             .expect("DeviceId object_path must contain a slash.");
         AdapterId::new(&self.object_path[0..index])
     }
+
+    /// Get the MAC address of this device, as encoded in its object path.
+    pub fn mac_address(&self) -> MacAddress {
+        let index = self
+            .object_path
+            .rfind('/')
+            .expect("DeviceId object_path must contain a slash.");
+        mac_address_from_node(&self.object_path[index + 1..])
+            .expect("DeviceId object_path must end with a MAC address.")
+    }
+
+    /// Get the D-Bus object path of the device, e.g. `"/org/bluez/hci0/dev_11_22_33_44_55_66"`.
+    pub fn object_path(&self) -> &str {
+        &self.object_path
+    }
 }
 
 impl From<DeviceId> for Path<'static> {
@@ -41,6 +61,39 @@ impl From<DeviceId> for Path<'static> {
     }
 }
 
+impl TryFrom<Path<'static>> for DeviceId {
+    type Error = ParseIdError;
+
+    fn try_from(object_path: Path<'static>) -> Result<Self, Self::Error> {
+        if is_device_path(&object_path) {
+            Ok(Self { object_path })
+        } else {
+            Err(ParseIdError::new("device", &object_path))
+        }
+    }
+}
+
+impl TryFrom<SerializedId> for DeviceId {
+    type Error = ParseIdError;
+
+    fn try_from(id: SerializedId) -> Result<Self, Self::Error> {
+        id.try_into_id()
+    }
+}
+
+impl FromStr for DeviceId {
+    type Err = ParseIdError;
+
+    /// Parse a device ID in the format produced by its `Display` implementation, e.g.
+    /// `"hci0/dev_11_22_33_44_55_66"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::new(path_from_display(s))
+            .ok()
+            .and_then(|object_path| object_path.try_into().ok())
+            .ok_or_else(|| ParseIdError::new("device", s))
+    }
+}
+
 impl Display for DeviceId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -113,9 +166,34 @@ pub struct DeviceInfo {
     pub modalias: Option<String>,
     // If set to true this device will be allowed to wake the host from system suspend.
     pub wake_allowed: bool,
+    /// The flags from the device's advertisement, if any.
+    pub advertising_flags: Option<AdvertisingFlags>,
 }
 
 impl DeviceInfo {
+    /// Get a handle for the device based on its address rather than its D-Bus object path.
+    ///
+    /// Once a device using resolvable private addresses is bonded, BlueZ reports its identity
+    /// address, so this stays the same even if the `DeviceId` changes when the device rotates its
+    /// address.
+    pub fn identity(&self) -> DeviceIdentity {
+        DeviceIdentity {
+            adapter: self.id.adapter(),
+            mac_address: self.mac_address,
+            address_type: self.address_type,
+        }
+    }
+
+    /// Estimate the path loss of the device's signal in dB, from the transmission power it
+    /// advertised and the RSSI it was received with.
+    ///
+    /// This increases with distance from the device, so may be used for rough proximity
+    /// estimates. It is `None` if either the TX power or RSSI isn't known, or if they are so far
+    /// out of range that the difference doesn't fit in an `i16`.
+    pub fn path_loss(&self) -> Option<i16> {
+        self.tx_power?.checked_sub(self.rssi?)
+    }
+
     pub(crate) fn from_properties(
         id: DeviceId,
         device_properties: OrgBluezDevice1Properties,
@@ -167,10 +245,58 @@ impl DeviceInfo {
                 .ok_or(BluetoothError::RequiredPropertyMissing("LegacyPairing"))?,
             modalias: device_properties.modalias().cloned(),
             wake_allowed: device_properties.wake_allowed().unwrap_or(false),
+            advertising_flags: device_properties
+                .advertising_flags()
+                .map(Vec::as_slice)
+                .and_then(convert_advertising_flags),
         })
     }
 }
 
+/// A handle for a Bluetooth device which identifies it by its (identity) address and the adapter
+/// it is known to, rather than by the D-Bus object path in its [`DeviceId`].
+///
+/// Use [`BluetoothSession::resolve_device`](crate::BluetoothSession::resolve_device) to find the
+/// current `DeviceId` for it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DeviceIdentity {
+    /// The adapter on which the device was discovered.
+    pub adapter: AdapterId,
+    /// The MAC address of the device. For a bonded device using resolvable private addresses this
+    /// is its identity address.
+    pub mac_address: MacAddress,
+    /// The type of the MAC address.
+    pub address_type: AddressType,
+}
+
+impl DeviceIdentity {
+    /// Find the device with this identity among the given devices, if any.
+    pub(crate) fn find(&self, devices: &[DeviceInfo]) -> Option<DeviceId> {
+        devices
+            .iter()
+            .find(|device| device.identity() == *self)
+            .map(|device| device.id.to_owned())
+    }
+}
+
+bitflags! {
+    /// The flags from the Flags AD type of a device's advertisement, which describe its
+    /// discoverability and the Bluetooth modes it supports.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct AdvertisingFlags: u8 {
+        /// LE Limited Discoverable Mode.
+        const LE_LIMITED_DISCOVERABLE = 0x01;
+        /// LE General Discoverable Mode.
+        const LE_GENERAL_DISCOVERABLE = 0x02;
+        /// BR/EDR (i.e. Bluetooth Classic) is not supported.
+        const BR_EDR_NOT_SUPPORTED = 0x04;
+        /// Simultaneous LE and BR/EDR to the same device is supported by the controller.
+        const SIMULTANEOUS_LE_BR_EDR_CONTROLLER = 0x08;
+        /// Simultaneous LE and BR/EDR to the same device is supported by the host.
+        const SIMULTANEOUS_LE_BR_EDR_HOST = 0x10;
+    }
+}
+
 /// MAC address type of a Bluetooth device.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum AddressType {
@@ -207,6 +333,65 @@ impl FromStr for AddressType {
     }
 }
 
+/// Conditions for which devices to include from [`devices_from_managed_objects`]. The default
+/// includes all devices.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct DeviceFilter<'a> {
+    /// Only include devices on the given adapter.
+    pub adapter: Option<&'a AdapterId>,
+    /// Only include devices which are currently connected.
+    pub connected: bool,
+    /// Only include devices which are currently paired.
+    pub paired: bool,
+    /// Only include devices which include the given service UUID in their advertisement or
+    /// resolved services.
+    pub service: Option<Uuid>,
+}
+
+impl DeviceFilter<'_> {
+    /// Check whether the device with the given ID and properties matches the filter, without
+    /// parsing all of its properties.
+    fn matches(&self, id: &DeviceId, device_properties: OrgBluezDevice1Properties) -> bool {
+        if let Some(adapter) = self.adapter {
+            if id.adapter() != *adapter {
+                return false;
+            }
+        }
+        if self.connected && device_properties.connected() != Some(true) {
+            return false;
+        }
+        if self.paired && device_properties.paired() != Some(true) {
+            return false;
+        }
+        if let Some(service) = self.service {
+            if !get_services(device_properties).contains(&service) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Parse information about the devices which match the given filter from the result of a
+/// `GetManagedObjects` call.
+///
+/// Devices whose properties can't be parsed are skipped.
+pub(crate) fn devices_from_managed_objects(
+    tree: HashMap<Path<'static>, HashMap<String, PropMap>>,
+    filter: DeviceFilter,
+) -> Vec<DeviceInfo> {
+    tree.into_iter()
+        .filter_map(|(object_path, interfaces)| {
+            let device_properties = OrgBluezDevice1Properties::from_interfaces(&interfaces)?;
+            let id = DeviceId { object_path };
+            if !filter.matches(&id, device_properties) {
+                return None;
+            }
+            DeviceInfo::from_properties(id, device_properties).ok()
+        })
+        .collect()
+}
+
 fn get_manufacturer_data(
     device_properties: OrgBluezDevice1Properties,
 ) -> Option<HashMap<u16, Vec<u8>>> {
@@ -215,7 +400,13 @@ fn get_manufacturer_data(
     ))
 }
 
-pub(crate) fn convert_manufacturer_data(
+/// Convert the value of a device's `ManufacturerData` property, as received over D-Bus, to a map
+/// from manufacturer ID to data. Entries whose values aren't byte arrays are skipped, with a
+/// warning logged.
+///
+/// This is the same conversion used for [`DeviceInfo::manufacturer_data`] and
+/// [`DeviceEvent::ManufacturerData`](crate::DeviceEvent::ManufacturerData).
+pub fn convert_manufacturer_data(
     data: &HashMap<u16, Variant<Box<dyn RefArg>>>,
 ) -> HashMap<u16, Vec<u8>> {
     data.iter()
@@ -236,7 +427,13 @@ fn get_service_data(
     Some(convert_service_data(device_properties.service_data()?))
 }
 
-pub(crate) fn convert_service_data(data: &PropMap) -> HashMap<Uuid, Vec<u8>> {
+/// Convert the value of a device's `ServiceData` property, as received over D-Bus, to a map from
+/// service UUID to data. Entries with invalid UUIDs or whose values aren't byte arrays are
+/// skipped, with a warning logged.
+///
+/// This is the same conversion used for [`DeviceInfo::service_data`] and
+/// [`DeviceEvent::ServiceData`](crate::DeviceEvent::ServiceData).
+pub fn convert_service_data(data: &PropMap) -> HashMap<Uuid, Vec<u8>> {
     data.iter()
         .filter_map(|(k, v)| match Uuid::parse_str(k) {
             Ok(uuid) => {
@@ -248,7 +445,7 @@ pub(crate) fn convert_service_data(data: &PropMap) -> HashMap<Uuid, Vec<u8>> {
                 }
             }
             Err(err) => {
-                log::warn!("Error parsing service data UUID: {}", err);
+                log::warn!("Error parsing UUID: {}", err);
                 None
             }
         })
@@ -257,19 +454,32 @@ pub(crate) fn convert_service_data(data: &PropMap) -> HashMap<Uuid, Vec<u8>> {
 
 fn get_services(device_properties: OrgBluezDevice1Properties) -> Vec<Uuid> {
     if let Some(uuids) = device_properties.uuids() {
-        convert_services(uuids)
+        convert_uuids(uuids)
     } else {
         vec![]
     }
 }
 
-pub(crate) fn convert_services(uuids: &[String]) -> Vec<Uuid> {
+/// Convert the value of a device's `AdvertisingFlags` property to typed flags. Only the first
+/// byte is used, as the later ones are reserved. Unknown flags are kept.
+pub(crate) fn convert_advertising_flags(flags: &[u8]) -> Option<AdvertisingFlags> {
+    flags
+        .first()
+        .map(|&flags| AdvertisingFlags::from_bits_retain(flags))
+}
+
+/// Convert a list of UUIDs as strings, as received over D-Bus in properties such as a device's
+/// `UUIDs`, to a list of UUIDs. Invalid UUIDs are skipped, with a warning logged.
+///
+/// This is the same conversion used for [`DeviceInfo::services`] and
+/// [`DeviceEvent::Services`](crate::DeviceEvent::Services).
+pub fn convert_uuids(uuids: &[String]) -> Vec<Uuid> {
     uuids
         .iter()
         .filter_map(|uuid| {
             Uuid::parse_str(uuid)
                 .map_err(|err| {
-                    log::warn!("Error parsing service data UUID: {}", err);
+                    log::warn!("Error parsing UUID {:?}: {}", uuid, err);
                     err
                 })
                 .ok()
@@ -296,6 +506,48 @@ mod tests {
         assert_eq!(device_id.to_string(), "hci0/dev_11_22_33_44_55_66");
     }
 
+    #[test]
+    fn from_str() {
+        let device_id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(device_id.to_string().parse(), Ok(device_id));
+        assert!("hci0".parse::<DeviceId>().is_err());
+        assert!("hci0/dev_11_22_33".parse::<DeviceId>().is_err());
+    }
+
+    #[test]
+    fn try_from_path() {
+        assert_eq!(
+            DeviceId::try_from(Path::from("/org/bluez/hci0/dev_11_22_33_44_55_66")),
+            Ok(DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66"))
+        );
+        assert_eq!(
+            DeviceId::try_from(Path::from("/org/bluez/hci0/service0012")),
+            Err(ParseIdError::new("device", "/org/bluez/hci0/service0012"))
+        );
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let device_id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let json = serde_json::to_string(&device_id).unwrap();
+        assert_eq!(
+            json,
+            r#"{"object_path":"/org/bluez/hci0/dev_11_22_33_44_55_66"}"#
+        );
+        assert_eq!(serde_json::from_str::<DeviceId>(&json).unwrap(), device_id);
+        assert!(serde_json::from_str::<DeviceId>(r#"{"object_path":"/org/bluez/hci0"}"#).is_err());
+        assert!(serde_json::from_str::<DeviceId>(r#"{"object_path":"not a path"}"#).is_err());
+    }
+
+    #[test]
+    fn device_mac_address() {
+        let device_id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_AA");
+        assert_eq!(
+            device_id.mac_address(),
+            "11:22:33:44:55:AA".parse().unwrap()
+        );
+    }
+
     #[test]
     fn service_data() {
         let uuid = uuid_from_u32(0x11223344);
@@ -334,24 +586,71 @@ mod tests {
     }
 
     #[test]
-    fn device_info_minimal() {
-        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        let mut device_properties: PropMap = HashMap::new();
-        device_properties.insert(
-            "Address".to_string(),
-            Variant(Box::new("00:11:22:33:44:55".to_string())),
+    fn convert_manufacturer_data_wrong_type() {
+        let mut manufacturer_data: HashMap<u16, Variant<Box<dyn RefArg>>> = HashMap::new();
+        manufacturer_data.insert(0x1122, Variant(Box::new(vec![1u8, 2, 3])));
+        manufacturer_data.insert(0x3344, Variant(Box::new("not bytes".to_string())));
+
+        let mut expected_manufacturer_data = HashMap::new();
+        expected_manufacturer_data.insert(0x1122, vec![1u8, 2, 3]);
+
+        assert_eq!(
+            convert_manufacturer_data(&manufacturer_data),
+            expected_manufacturer_data
+        );
+    }
+
+    #[test]
+    fn convert_service_data_invalid() {
+        let uuid = uuid_from_u32(0x11223344);
+        let mut service_data: PropMap = HashMap::new();
+        service_data.insert(uuid.to_string(), Variant(Box::new(vec![1u8, 2, 3])));
+        service_data.insert("invalid".to_string(), Variant(Box::new(vec![4u8])));
+        service_data.insert(
+            uuid_from_u32(0x55667788).to_string(),
+            Variant(Box::new(42u32)),
         );
+
+        let mut expected_service_data = HashMap::new();
+        expected_service_data.insert(uuid, vec![1u8, 2, 3]);
+
+        assert_eq!(convert_service_data(&service_data), expected_service_data);
+    }
+
+    #[test]
+    fn convert_uuids_invalid() {
+        let uuid = uuid_from_u32(0x11223344);
+        assert_eq!(
+            convert_uuids(&[uuid.to_string(), "invalid".to_string()]),
+            vec![uuid]
+        );
+    }
+
+    #[test]
+    fn advertising_flags() {
+        let mut device_properties = minimal_device_properties();
         device_properties.insert(
-            "AddressType".to_string(),
-            Variant(Box::new("public".to_string())),
+            "AdvertisingFlags".to_string(),
+            Variant(Box::new(vec![0x06u8])),
         );
-        device_properties.insert("Paired".to_string(), Variant(Box::new(false)));
-        device_properties.insert("Connected".to_string(), Variant(Box::new(false)));
-        device_properties.insert("ServicesResolved".to_string(), Variant(Box::new(false)));
-        device_properties.insert("Bonded".to_string(), Variant(Box::new(false)));
-        device_properties.insert("Trusted".to_string(), Variant(Box::new(false)));
-        device_properties.insert("Blocked".to_string(), Variant(Box::new(false)));
-        device_properties.insert("LegacyPairing".to_string(), Variant(Box::new(false)));
+        let device = DeviceInfo::from_properties(
+            DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66"),
+            OrgBluezDevice1Properties(&device_properties),
+        )
+        .unwrap();
+        assert_eq!(
+            device.advertising_flags,
+            Some(
+                AdvertisingFlags::LE_GENERAL_DISCOVERABLE | AdvertisingFlags::BR_EDR_NOT_SUPPORTED
+            )
+        );
+        assert_eq!(convert_advertising_flags(&[]), None);
+    }
+
+    #[test]
+    fn device_info_minimal() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let device_properties = minimal_device_properties();
 
         let device =
             DeviceInfo::from_properties(id.clone(), OrgBluezDevice1Properties(&device_properties))
@@ -381,10 +680,175 @@ mod tests {
                 legacy_pairing: false,
                 modalias: None,
                 wake_allowed: false,
+                advertising_flags: None,
             }
         )
     }
 
+    #[test]
+    fn device_info_path_loss() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let mut device_properties = minimal_device_properties();
+        device_properties.insert("RSSI".to_string(), Variant(Box::new(-70i16)));
+
+        let device =
+            DeviceInfo::from_properties(id.clone(), OrgBluezDevice1Properties(&device_properties))
+                .unwrap();
+        assert_eq!(device.path_loss(), None);
+
+        device_properties.insert("TxPower".to_string(), Variant(Box::new(4i16)));
+        let device =
+            DeviceInfo::from_properties(id.clone(), OrgBluezDevice1Properties(&device_properties))
+                .unwrap();
+        assert_eq!(device.tx_power, Some(4));
+        assert_eq!(device.path_loss(), Some(74));
+
+        device_properties.insert("RSSI".to_string(), Variant(Box::new(i16::MIN)));
+        let device =
+            DeviceInfo::from_properties(id, OrgBluezDevice1Properties(&device_properties)).unwrap();
+        assert_eq!(device.path_loss(), None);
+    }
+
+    #[test]
+    fn device_identity_find() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let device = DeviceInfo::from_properties(
+            id.clone(),
+            OrgBluezDevice1Properties(&minimal_device_properties()),
+        )
+        .unwrap();
+        let identity = device.identity();
+        assert_eq!(identity.adapter, AdapterId::new("/org/bluez/hci0"));
+
+        // The device is found even if its ID has changed.
+        let renamed = DeviceInfo {
+            id: DeviceId::new("/org/bluez/hci0/dev_AA_BB_CC_DD_EE_FF"),
+            ..device.clone()
+        };
+        assert_eq!(identity.find(std::slice::from_ref(&device)), Some(id));
+        assert_eq!(
+            identity.find(&[renamed]),
+            Some(DeviceId::new("/org/bluez/hci0/dev_AA_BB_CC_DD_EE_FF"))
+        );
+
+        let other_adapter = DeviceIdentity {
+            adapter: AdapterId::new("/org/bluez/hci1"),
+            ..identity
+        };
+        assert_eq!(other_adapter.find(&[device]), None);
+    }
+
+    #[test]
+    fn devices_from_managed_objects_adapter() {
+        let managed_objects = || {
+            let mut tree = HashMap::new();
+            let mut adapter_interfaces = HashMap::new();
+            adapter_interfaces.insert("org.bluez.Adapter1".to_string(), HashMap::new());
+            tree.insert(Path::from("/org/bluez/hci0"), adapter_interfaces);
+            for object_path in &[
+                "/org/bluez/hci0/dev_11_22_33_44_55_66",
+                "/org/bluez/hci1/dev_11_22_33_44_55_66",
+            ] {
+                let mut interfaces = HashMap::new();
+                interfaces.insert("org.bluez.Device1".to_string(), minimal_device_properties());
+                tree.insert(Path::from(*object_path), interfaces);
+            }
+            tree
+        };
+
+        let mut all_devices: Vec<DeviceId> =
+            devices_from_managed_objects(managed_objects(), DeviceFilter::default())
+                .into_iter()
+                .map(|device| device.id)
+                .collect();
+        all_devices.sort();
+        assert_eq!(
+            all_devices,
+            vec![
+                DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66"),
+                DeviceId::new("/org/bluez/hci1/dev_11_22_33_44_55_66"),
+            ]
+        );
+
+        let adapter_id = AdapterId::new("/org/bluez/hci1");
+        let adapter_devices = devices_from_managed_objects(
+            managed_objects(),
+            DeviceFilter {
+                adapter: Some(&adapter_id),
+                ..Default::default()
+            },
+        );
+        assert_eq!(adapter_devices.len(), 1);
+        assert_eq!(
+            adapter_devices[0].id,
+            DeviceId::new("/org/bluez/hci1/dev_11_22_33_44_55_66")
+        );
+    }
+
+    #[test]
+    fn devices_from_managed_objects_filters() {
+        let uuid = uuid_from_u32(0x11223344);
+        let managed_objects = || {
+            let mut connected = minimal_device_properties();
+            connected.insert("Connected".to_string(), Variant(Box::new(true)));
+            let mut paired = minimal_device_properties();
+            paired.insert("Paired".to_string(), Variant(Box::new(true)));
+            let mut advertising = minimal_device_properties();
+            advertising.insert(
+                "UUIDs".to_string(),
+                Variant(Box::new(vec![uuid.to_string()])),
+            );
+
+            let mut tree = HashMap::new();
+            for (object_path, device_properties) in [
+                ("/org/bluez/hci0/dev_11_11_11_11_11_11", connected),
+                ("/org/bluez/hci0/dev_22_22_22_22_22_22", paired),
+                ("/org/bluez/hci0/dev_33_33_33_33_33_33", advertising),
+            ] {
+                let mut interfaces = HashMap::new();
+                interfaces.insert("org.bluez.Device1".to_string(), device_properties);
+                tree.insert(Path::from(object_path), interfaces);
+            }
+            tree
+        };
+        let device_ids = |filter| -> Vec<DeviceId> {
+            devices_from_managed_objects(managed_objects(), filter)
+                .into_iter()
+                .map(|device| device.id)
+                .collect()
+        };
+
+        assert_eq!(
+            device_ids(DeviceFilter {
+                connected: true,
+                ..Default::default()
+            }),
+            vec![DeviceId::new("/org/bluez/hci0/dev_11_11_11_11_11_11")]
+        );
+        assert_eq!(
+            device_ids(DeviceFilter {
+                paired: true,
+                ..Default::default()
+            }),
+            vec![DeviceId::new("/org/bluez/hci0/dev_22_22_22_22_22_22")]
+        );
+        assert_eq!(
+            device_ids(DeviceFilter {
+                service: Some(uuid),
+                ..Default::default()
+            }),
+            vec![DeviceId::new("/org/bluez/hci0/dev_33_33_33_33_33_33")]
+        );
+        assert_eq!(
+            device_ids(DeviceFilter {
+                connected: true,
+                paired: true,
+                ..Default::default()
+            }),
+            vec![]
+        );
+    }
+
     #[test]
     fn get_services_none() {
         let device_properties: PropMap = HashMap::new();
@@ -417,4 +881,24 @@ mod tests {
             );
         }
     }
+
+    fn minimal_device_properties() -> PropMap {
+        let mut device_properties: PropMap = HashMap::new();
+        device_properties.insert(
+            "Address".to_string(),
+            Variant(Box::new("00:11:22:33:44:55".to_string())),
+        );
+        device_properties.insert(
+            "AddressType".to_string(),
+            Variant(Box::new("public".to_string())),
+        );
+        device_properties.insert("Paired".to_string(), Variant(Box::new(false)));
+        device_properties.insert("Connected".to_string(), Variant(Box::new(false)));
+        device_properties.insert("ServicesResolved".to_string(), Variant(Box::new(false)));
+        device_properties.insert("Bonded".to_string(), Variant(Box::new(false)));
+        device_properties.insert("Trusted".to_string(), Variant(Box::new(false)));
+        device_properties.insert("Blocked".to_string(), Variant(Box::new(false)));
+        device_properties.insert("LegacyPairing".to_string(), Variant(Box::new(false)));
+        device_properties
+    }
 }
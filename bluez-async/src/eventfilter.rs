@@ -0,0 +1,438 @@
+use bitflags::bitflags;
+use bluez_generated::OrgBluezDevice1Properties;
+use dbus::message::{MatchRule, SignalArgs};
+use dbus::nonblock::stdintf::org_freedesktop_dbus::ObjectManagerInterfacesAdded;
+use dbus::{Message, Path};
+use futures::stream::{self, Stream, StreamExt};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::objectpath::is_adapter_path;
+use crate::{
+    convert_uuids, AdapterId, BluetoothEvent, CharacteristicId, DeviceEvent, DeviceId, DeviceInfo,
+    ServiceId,
+};
+
+bitflags! {
+    /// The kinds of events to include in an [`EventFilter`].
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct EventKinds: u8 {
+        /// Events for adapters.
+        const ADAPTER = 0x01;
+        /// `DeviceEvent::Discovered` events.
+        const DEVICE_DISCOVERED = 0x02;
+        /// Events for devices other than `DeviceEvent::Discovered`.
+        const DEVICE = 0x04;
+        /// Events for GATT characteristics.
+        const CHARACTERISTIC = 0x08;
+    }
+}
+
+/// A set of conditions for which events to include in a stream from
+/// [`BluetoothSession::events`](crate::BluetoothSession::events).
+///
+/// The default filter includes all events. Each condition added narrows it down further, except
+/// that only one object can be given: each call to [`adapter`](Self::adapter),
+/// [`device`](Self::device), [`service`](Self::service) or
+/// [`characteristic`](Self::characteristic) replaces the object from any earlier call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventFilter {
+    object: Option<Path<'static>>,
+    service_uuid: Option<Uuid>,
+    kinds: EventKinds,
+}
+
+impl Default for EventFilter {
+    fn default() -> Self {
+        Self {
+            object: None,
+            service_uuid: None,
+            kinds: EventKinds::all(),
+        }
+    }
+}
+
+impl EventFilter {
+    /// Create a filter which includes all events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only include events for the given adapter and devices discovered on it.
+    pub fn adapter(self, adapter: &AdapterId) -> Self {
+        self.object(adapter.to_owned())
+    }
+
+    /// Only include events for the given device and its characteristics.
+    ///
+    /// `DeviceEvent::Discovered` is considered an event for the adapter rather than the device,
+    /// so isn't included.
+    pub fn device(self, device: &DeviceId) -> Self {
+        self.object(device.to_owned())
+    }
+
+    /// Only include events for characteristics of the given GATT service.
+    pub fn service(self, service: &ServiceId) -> Self {
+        self.object(service.to_owned())
+    }
+
+    /// Only include events for the given characteristic.
+    pub fn characteristic(self, characteristic: &CharacteristicId) -> Self {
+        self.object(characteristic.to_owned())
+    }
+
+    /// Only include adapter events, and events for devices (and their characteristics) which
+    /// advertise the given service UUID.
+    pub fn service_uuid(mut self, uuid: Uuid) -> Self {
+        self.service_uuid = Some(uuid);
+        self
+    }
+
+    /// Only include events of the given kinds.
+    pub fn kinds(mut self, kinds: EventKinds) -> Self {
+        self.kinds = kinds;
+        self
+    }
+
+    /// Only include events for the given object and those under it, replacing any previous
+    /// object.
+    fn object(mut self, object: impl Into<Path<'static>>) -> Self {
+        self.object = Some(object.into());
+        self
+    }
+
+    /// Get the minimal set of D-Bus match rules needed to receive all events which match the
+    /// filter.
+    pub(crate) fn match_rules(&self) -> Vec<MatchRule<'static>> {
+        // Devices can only be discovered on an adapter, so there's no need to watch for new
+        // objects if the filter is for anything more specific.
+        let device_discovery = self.kinds.contains(EventKinds::DEVICE_DISCOVERED)
+            && match &self.object {
+                Some(object) => is_adapter_path(object),
+                None => true,
+            };
+        BluetoothEvent::match_rules(self.object.clone(), device_discovery)
+    }
+
+    pub(crate) fn has_service_uuid(&self) -> bool {
+        self.service_uuid.is_some()
+    }
+
+    /// Get the initial state to pass to [`matches`](Self::matches), given a list of known
+    /// devices.
+    pub(crate) fn initial_devices(&self, devices: &[DeviceInfo]) -> HashSet<DeviceId> {
+        match self.service_uuid {
+            Some(uuid) => devices
+                .iter()
+                .filter(|device| device.services.contains(&uuid))
+                .map(|device| device.id.to_owned())
+                .collect(),
+            None => HashSet::new(),
+        }
+    }
+
+    /// Convert a stream of D-Bus messages to a stream of the events from them which match the
+    /// filter, starting from the given set of devices known to advertise the service UUID.
+    pub(crate) fn filter_messages(
+        self,
+        messages: impl Stream<Item = Message>,
+        mut devices: HashSet<DeviceId>,
+    ) -> impl Stream<Item = BluetoothEvent> {
+        messages
+            .flat_map(move |message| stream::iter(self.message_to_events(message, &mut devices)))
+    }
+
+    /// Parse the events from the given D-Bus message, and return those which match the filter.
+    ///
+    /// If the filter has a service UUID then `devices` is used to keep track of which devices
+    /// advertise it, so the same set should be passed for each message in a stream.
+    pub(crate) fn message_to_events(
+        &self,
+        message: Message,
+        devices: &mut HashSet<DeviceId>,
+    ) -> Vec<BluetoothEvent> {
+        if let Some(uuid) = self.service_uuid {
+            // `DeviceEvent::Discovered` doesn't include the properties of the new device, so check
+            // its services from the signal before they are lost.
+            if let Some(interfaces_added) = ObjectManagerInterfacesAdded::from_message(&message) {
+                let uuids =
+                    OrgBluezDevice1Properties::from_interfaces(&interfaces_added.interfaces)
+                        .and_then(|device| device.uuids().map(Vec::as_slice).map(convert_uuids));
+                if uuids.is_some_and(|uuids| uuids.contains(&uuid)) {
+                    devices.insert(DeviceId {
+                        object_path: interfaces_added.object,
+                    });
+                }
+            }
+        }
+        BluetoothEvent::message_to_events(message)
+            .into_iter()
+            .filter(|event| self.matches(event, devices))
+            .collect()
+    }
+
+    /// Check whether the given event matches the filter.
+    ///
+    /// If the filter has a service UUID then `devices` is used to keep track of which devices
+    /// advertise it, so the same set should be passed for each event in a stream.
+    pub(crate) fn matches(&self, event: &BluetoothEvent, devices: &mut HashSet<DeviceId>) -> bool {
+        let (path, kind, device) = match event {
+            BluetoothEvent::Adapter { id, .. } => (&id.object_path, EventKinds::ADAPTER, None),
+            BluetoothEvent::Device { id, event } => {
+                if let Some(uuid) = self.service_uuid {
+                    update_service_devices(devices, id, event, uuid);
+                }
+                let kind = if matches!(event, DeviceEvent::Discovered) {
+                    EventKinds::DEVICE_DISCOVERED
+                } else {
+                    EventKinds::DEVICE
+                };
+                (&id.object_path, kind, Some(id.to_owned()))
+            }
+            BluetoothEvent::Characteristic { id, .. } => (
+                &id.object_path,
+                EventKinds::CHARACTERISTIC,
+                Some(id.device()),
+            ),
+        };
+        if !self.kinds.contains(kind) {
+            return false;
+        }
+        if let Some(object) = &self.object {
+            // Discovered events are only included for the adapter, not the device itself.
+            if !is_descendant(path, object)
+                || (kind == EventKinds::DEVICE_DISCOVERED && path == object)
+            {
+                return false;
+            }
+        }
+        match (self.service_uuid, device) {
+            (Some(_), Some(device)) => devices.contains(&device),
+            _ => true,
+        }
+    }
+}
+
+/// Update the set of devices known to advertise the given service UUID from a device event.
+fn update_service_devices(
+    devices: &mut HashSet<DeviceId>,
+    id: &DeviceId,
+    event: &DeviceEvent,
+    uuid: Uuid,
+) {
+    match event {
+        DeviceEvent::Services { services } => {
+            if services.contains(&uuid) {
+                devices.insert(id.to_owned());
+            } else {
+                devices.remove(id);
+            }
+        }
+        DeviceEvent::ServiceData { service_data } if service_data.contains_key(&uuid) => {
+            devices.insert(id.to_owned());
+        }
+        _ => {}
+    }
+}
+
+/// Check whether the given path is the same as or under the given object path.
+fn is_descendant(path: &str, object: &str) -> bool {
+    match path.strip_prefix(object) {
+        Some(rest) => rest.is_empty() || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{uuid_from_u16, AdapterEvent, CharacteristicEvent};
+    use dbus::arg::{PropMap, Variant};
+    use dbus::nonblock::stdintf::org_freedesktop_dbus::PropertiesPropertiesChanged;
+    use std::collections::HashMap;
+
+    fn device_event(path: &str, event: DeviceEvent) -> BluetoothEvent {
+        BluetoothEvent::Device {
+            id: DeviceId::new(path),
+            event,
+        }
+    }
+
+    #[test]
+    fn match_rules_discovery() {
+        let adapter = AdapterId::new("/org/bluez/hci0");
+        let device = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(EventFilter::new().match_rules().len(), 2);
+        assert_eq!(EventFilter::new().adapter(&adapter).match_rules().len(), 2);
+        assert_eq!(EventFilter::new().device(&device).match_rules().len(), 1);
+        assert_eq!(
+            EventFilter::new()
+                .kinds(EventKinds::DEVICE)
+                .match_rules()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn matches_object() {
+        let mut devices = HashSet::new();
+        let adapter = AdapterId::new("/org/bluez/hci0");
+        let filter = EventFilter::new().adapter(&adapter);
+        let discovered = device_event(
+            "/org/bluez/hci0/dev_11_22_33_44_55_66",
+            DeviceEvent::Discovered,
+        );
+        let other_discovered = device_event(
+            "/org/bluez/hci1/dev_11_22_33_44_55_66",
+            DeviceEvent::Discovered,
+        );
+        let powered = BluetoothEvent::Adapter {
+            id: adapter,
+            event: AdapterEvent::Powered { powered: true },
+        };
+        assert!(filter.matches(&discovered, &mut devices));
+        assert!(!filter.matches(&other_discovered, &mut devices));
+        assert!(filter.matches(&powered, &mut devices));
+
+        let device = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let filter = EventFilter::new().device(&device);
+        assert!(!filter.matches(&discovered, &mut devices));
+        assert!(!filter.matches(&powered, &mut devices));
+        assert!(filter.matches(
+            &device_event(
+                "/org/bluez/hci0/dev_11_22_33_44_55_66",
+                DeviceEvent::Rssi { rssi: 42 }
+            ),
+            &mut devices
+        ));
+        assert!(!filter.matches(
+            &device_event(
+                "/org/bluez/hci0/dev_11_22_33_44_55_667",
+                DeviceEvent::Rssi { rssi: 42 }
+            ),
+            &mut devices
+        ));
+    }
+
+    #[test]
+    fn object_replaces_previous() {
+        let mut devices = HashSet::new();
+        let adapter = AdapterId::new("/org/bluez/hci0");
+        let device = DeviceId::new("/org/bluez/hci1/dev_11_22_33_44_55_66");
+        let filter = EventFilter::new().adapter(&adapter).device(&device);
+        assert_eq!(filter, EventFilter::new().device(&device));
+        assert!(filter.matches(
+            &device_event(
+                "/org/bluez/hci1/dev_11_22_33_44_55_66",
+                DeviceEvent::Rssi { rssi: 42 }
+            ),
+            &mut devices
+        ));
+        assert!(!filter.matches(
+            &device_event(
+                "/org/bluez/hci0/dev_11_22_33_44_55_66",
+                DeviceEvent::Rssi { rssi: 42 }
+            ),
+            &mut devices
+        ));
+    }
+
+    #[test]
+    fn matches_kinds() {
+        let mut devices = HashSet::new();
+        let filter = EventFilter::new().kinds(EventKinds::CHARACTERISTIC);
+        let value = BluetoothEvent::Characteristic {
+            id: CharacteristicId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034"),
+            event: CharacteristicEvent::Value { value: vec![1] },
+        };
+        assert!(filter.matches(&value, &mut devices));
+        assert!(!filter.matches(
+            &device_event(
+                "/org/bluez/hci0/dev_11_22_33_44_55_66",
+                DeviceEvent::Rssi { rssi: 42 }
+            ),
+            &mut devices
+        ));
+    }
+
+    #[test]
+    fn matches_service_uuid() {
+        let uuid = uuid_from_u16(0x180d);
+        let filter = EventFilter::new().service_uuid(uuid);
+        let mut devices = HashSet::new();
+        let rssi = device_event(
+            "/org/bluez/hci0/dev_11_22_33_44_55_66",
+            DeviceEvent::Rssi { rssi: 42 },
+        );
+        assert!(!filter.matches(&rssi, &mut devices));
+
+        let services = device_event(
+            "/org/bluez/hci0/dev_11_22_33_44_55_66",
+            DeviceEvent::Services {
+                services: vec![uuid],
+            },
+        );
+        assert!(filter.matches(&services, &mut devices));
+        assert!(filter.matches(&rssi, &mut devices));
+
+        let services = device_event(
+            "/org/bluez/hci0/dev_11_22_33_44_55_66",
+            DeviceEvent::Services { services: vec![] },
+        );
+        assert!(!filter.matches(&services, &mut devices));
+        assert!(!filter.matches(&rssi, &mut devices));
+    }
+
+    #[test]
+    fn matches_service_uuid_discovered() {
+        let uuid = uuid_from_u16(0x180d);
+        let filter = EventFilter::new().service_uuid(uuid);
+        let mut devices = HashSet::new();
+        let device_path = "/org/bluez/hci0/dev_11_22_33_44_55_66";
+        let other_device_path = "/org/bluez/hci0/dev_66_55_44_33_22_11";
+
+        assert_eq!(
+            filter.message_to_events(new_device_message(device_path, &[uuid]), &mut devices),
+            vec![device_event(device_path, DeviceEvent::Discovered)]
+        );
+        assert_eq!(
+            filter.message_to_events(new_device_message(other_device_path, &[]), &mut devices),
+            vec![]
+        );
+
+        // Later events for the device with the service should be included, but not the other.
+        assert_eq!(
+            filter.message_to_events(device_rssi_message(device_path, 42), &mut devices),
+            vec![device_event(device_path, DeviceEvent::Rssi { rssi: 42 })]
+        );
+        assert_eq!(
+            filter.message_to_events(device_rssi_message(other_device_path, 42), &mut devices),
+            vec![]
+        );
+    }
+
+    fn new_device_message(device_path: &'static str, services: &[Uuid]) -> Message {
+        let uuids: Vec<String> = services.iter().map(Uuid::to_string).collect();
+        let mut properties: PropMap = HashMap::new();
+        properties.insert("UUIDs".to_string(), Variant(Box::new(uuids)));
+        let mut interfaces = HashMap::new();
+        interfaces.insert("org.bluez.Device1".to_string(), properties);
+        let interfaces_added = ObjectManagerInterfacesAdded {
+            object: device_path.into(),
+            interfaces,
+        };
+        interfaces_added.to_emit_message(&"/".into())
+    }
+
+    fn device_rssi_message(device_path: &'static str, rssi: i16) -> Message {
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert("RSSI".to_string(), Variant(Box::new(rssi)));
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.Device1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        properties_changed.to_emit_message(&device_path.into())
+    }
+}
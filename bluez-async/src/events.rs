@@ -10,8 +10,10 @@ use dbus::{Message, Path};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use super::device::{convert_manufacturer_data, convert_service_data, convert_services};
-use super::{AdapterId, CharacteristicId, DeviceId};
+use super::device::{
+    convert_advertising_flags, convert_manufacturer_data, convert_service_data, convert_uuids,
+};
+use super::{AdapterId, AdvertisingFlags, CharacteristicId, DeviceId};
 
 /// An event relating to a Bluetooth device or adapter.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -57,8 +59,17 @@ pub enum DeviceEvent {
     Discovered,
     /// The device has connected or disconnected.
     Connected { connected: bool },
+    /// The device has been paired or unpaired.
+    Paired { paired: bool },
+    /// The device's pairing has been bonded, i.e. its keys stored for later connections, or the
+    /// bond has been removed.
+    Bonded { bonded: bool },
     /// A new value is available for the RSSI of the device.
     Rssi { rssi: i16 },
+    /// A new value is available for the transmission power level advertised by the device. Along
+    /// with the RSSI this can be used to estimate the path loss, as in
+    /// [`DeviceInfo::path_loss`](crate::DeviceInfo::path_loss).
+    TxPower { tx_power: i16 },
     /// A new value is available for the manufacturer-specific advertisement data of the device.
     ManufacturerData {
         /// The manufacturer-specific advertisement data. The keys are 'manufacturer IDs'.
@@ -76,6 +87,11 @@ pub enum DeviceEvent {
     },
     /// Service discovery has completed.
     ServicesResolved,
+    /// New advertising flags have been received from the device.
+    AdvertisingFlags { flags: AdvertisingFlags },
+    /// The device is still reported as connected, but has stopped responding to liveness pings
+    /// from a [watchdog](crate::BluetoothSession::device_watchdog).
+    Unresponsive,
 }
 
 /// Details of an event related to a GATT characteristic.
@@ -195,12 +211,30 @@ impl BluetoothEvent {
                         event: DeviceEvent::Connected { connected },
                     });
                 }
+                if let Some(paired) = device.paired() {
+                    events.push(BluetoothEvent::Device {
+                        id: id.clone(),
+                        event: DeviceEvent::Paired { paired },
+                    });
+                }
+                if let Some(bonded) = device.bonded() {
+                    events.push(BluetoothEvent::Device {
+                        id: id.clone(),
+                        event: DeviceEvent::Bonded { bonded },
+                    });
+                }
                 if let Some(rssi) = device.rssi() {
                     events.push(BluetoothEvent::Device {
                         id: id.clone(),
                         event: DeviceEvent::Rssi { rssi },
                     });
                 }
+                if let Some(tx_power) = device.tx_power() {
+                    events.push(BluetoothEvent::Device {
+                        id: id.clone(),
+                        event: DeviceEvent::TxPower { tx_power },
+                    });
+                }
                 if let Some(manufacturer_data) = device.manufacturer_data() {
                     events.push(BluetoothEvent::Device {
                         id: id.clone(),
@@ -221,10 +255,20 @@ impl BluetoothEvent {
                     events.push(BluetoothEvent::Device {
                         id: id.clone(),
                         event: DeviceEvent::Services {
-                            services: convert_services(services),
+                            services: convert_uuids(services),
                         },
                     })
                 }
+                if let Some(flags) = device
+                    .advertising_flags()
+                    .map(Vec::as_slice)
+                    .and_then(convert_advertising_flags)
+                {
+                    events.push(BluetoothEvent::Device {
+                        id: id.clone(),
+                        event: DeviceEvent::AdvertisingFlags { flags },
+                    });
+                }
                 if device.services_resolved() == Some(true) {
                     events.push(BluetoothEvent::Device {
                         id,
@@ -285,6 +329,82 @@ mod tests {
         )
     }
 
+    #[test]
+    fn device_tx_power() {
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert("TxPower".to_string(), Variant(Box::new(4i16)));
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.Device1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        let message =
+            properties_changed.to_emit_message(&"/org/bluez/hci0/dev_11_22_33_44_55_66".into());
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![BluetoothEvent::Device {
+                id,
+                event: DeviceEvent::TxPower { tx_power: 4 }
+            }]
+        )
+    }
+
+    #[test]
+    fn device_advertising_flags() {
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert(
+            "AdvertisingFlags".to_string(),
+            Variant(Box::new(vec![0x06u8])),
+        );
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.Device1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        let message =
+            properties_changed.to_emit_message(&"/org/bluez/hci0/dev_11_22_33_44_55_66".into());
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![BluetoothEvent::Device {
+                id,
+                event: DeviceEvent::AdvertisingFlags {
+                    flags: AdvertisingFlags::LE_GENERAL_DISCOVERABLE
+                        | AdvertisingFlags::BR_EDR_NOT_SUPPORTED
+                }
+            }]
+        )
+    }
+
+    #[test]
+    fn device_paired_bonded() {
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert("Paired".to_string(), Variant(Box::new(true)));
+        changed_properties.insert("Bonded".to_string(), Variant(Box::new(true)));
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.Device1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        let message =
+            properties_changed.to_emit_message(&"/org/bluez/hci0/dev_11_22_33_44_55_66".into());
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![
+                BluetoothEvent::Device {
+                    id: id.clone(),
+                    event: DeviceEvent::Paired { paired: true }
+                },
+                BluetoothEvent::Device {
+                    id,
+                    event: DeviceEvent::Bonded { bonded: true }
+                }
+            ]
+        )
+    }
+
     #[test]
     fn device_manufacturer_data() {
         let mut manufacturer_data = HashMap::new();
@@ -323,8 +443,7 @@ mod tests {
 
     #[test]
     fn device_services() {
-        let mut services = Vec::new();
-        services.push(uuid_from_u32(0x11223344));
+        let services = vec![uuid_from_u32(0x11223344)];
         let message =
             device_services_message("/org/bluez/hci0/dev_11_22_33_44_55_66", services.clone());
         let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
@@ -373,19 +492,19 @@ mod tests {
         let match_rules = BluetoothEvent::match_rules(None::<DeviceId>, true);
 
         let message = new_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = adapter_powered_message("/org/bluez/hci0", true);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = device_rssi_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = characteristic_value_message(
             "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034",
-            &vec![1, 2, 3],
+            &[1, 2, 3],
         );
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
     }
 
     #[test]
@@ -394,19 +513,19 @@ mod tests {
         let match_rules = BluetoothEvent::match_rules(Some(id), false);
 
         let message = new_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = adapter_powered_message("/org/bluez/hci0", true);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = device_rssi_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = characteristic_value_message(
             "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034",
-            &vec![1, 2, 3],
+            &[1, 2, 3],
         );
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
     }
 
     #[test]
@@ -415,19 +534,19 @@ mod tests {
         let match_rules = BluetoothEvent::match_rules(Some(id), false);
 
         let message = new_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = adapter_powered_message("/org/bluez/hci0", true);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = device_rssi_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = characteristic_value_message(
             "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034",
-            &vec![1, 2, 3],
+            &[1, 2, 3],
         );
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
     }
 
     #[test]
@@ -437,19 +556,19 @@ mod tests {
         let match_rules = BluetoothEvent::match_rules(Some(id), false);
 
         let message = new_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = adapter_powered_message("/org/bluez/hci0", true);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = device_rssi_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = characteristic_value_message(
             "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034",
-            &vec![1, 2, 3],
+            &[1, 2, 3],
         );
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
     }
 
     fn new_device_message(device_path: &'static str) -> Message {
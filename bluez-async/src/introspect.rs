@@ -1,76 +1,118 @@
+//! Types for the parsed results of introspecting D-Bus objects exported by BlueZ, to help with
+//! debugging which interfaces, methods and properties a particular BlueZ version supports.
+
 use dbus::nonblock::stdintf::org_freedesktop_dbus::Introspectable;
 use serde::Deserialize;
+use std::fmt::{self, Display, Formatter};
+use std::future::Future;
 
 use super::BluetoothError;
 
+/// A D-Bus object, as returned by introspection.
+///
+/// The `Display` implementation pretty-prints the object and its interfaces and child nodes as
+/// an indented tree.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Node {
+    /// The name of the node, relative to its parent. This is `None` for the object which was
+    /// introspected.
     pub name: Option<String>,
+    /// The interfaces which the object implements.
     #[serde(rename = "interface", default)]
     pub interfaces: Vec<Interface>,
+    /// The child nodes of the object. Their interfaces and children aren't included.
     #[serde(rename = "node", default)]
     pub nodes: Vec<Node>,
 }
 
+/// A D-Bus interface implemented by an object.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Interface {
+    /// The name of the interface, e.g. `"org.bluez.Device1"`.
     pub name: String,
+    /// The methods of the interface.
     #[serde(rename = "method", default)]
     pub methods: Vec<Method>,
+    /// The signals of the interface.
     #[serde(rename = "signal", default)]
     pub signals: Vec<Signal>,
+    /// The properties of the interface.
     #[serde(rename = "property", default)]
     pub properties: Vec<Property>,
+    /// Annotations on the interface.
     #[serde(rename = "annotation", default)]
     pub annotations: Vec<Annotation>,
 }
 
+/// A method of a D-Bus interface.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Method {
+    /// The name of the method.
     pub name: String,
+    /// The input and output arguments of the method.
     #[serde(rename = "arg", default)]
     pub args: Vec<MethodArg>,
+    /// Annotations on the method.
     #[serde(rename = "annotation", default)]
     pub annotations: Vec<Annotation>,
 }
 
+/// A signal of a D-Bus interface.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Signal {
+    /// The name of the signal.
     pub name: String,
+    /// The arguments of the signal.
     #[serde(rename = "arg", default)]
     pub args: Vec<SignalArg>,
+    /// Annotations on the signal.
     #[serde(rename = "annotation", default)]
     pub annotations: Vec<Annotation>,
 }
 
+/// A property of a D-Bus interface.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Property {
+    /// The name of the property.
     pub name: String,
+    /// The D-Bus type signature of the property.
     #[serde(rename = "type")]
     pub dbustype: String,
+    /// Whether the property can be read and written.
     pub access: Access,
+    /// Annotations on the property.
     #[serde(rename = "annotation", default)]
     pub annotations: Vec<Annotation>,
 }
 
+/// An argument of a D-Bus method.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct MethodArg {
+    /// The name of the argument, if it has one.
     pub name: Option<String>,
+    /// The D-Bus type signature of the argument.
     #[serde(rename = "type")]
     pub dbustype: String,
+    /// Whether this is an input or output argument.
     #[serde(default = "default_method_arg_direction")]
     pub direction: Direction,
+    /// Annotations on the argument.
     #[serde(rename = "annotation", default)]
     pub annotations: Vec<Annotation>,
 }
 
+/// An argument of a D-Bus signal.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct SignalArg {
+    /// The name of the argument, if it has one.
     pub name: Option<String>,
+    /// The D-Bus type signature of the argument.
     #[serde(rename = "type")]
     pub dbustype: String,
+    /// The direction of the argument, which is always `Out` for signals.
     #[serde(default = "default_signal_arg_direction")]
     pub direction: Direction,
+    /// Annotations on the argument.
     #[serde(rename = "annotation", default)]
     pub annotations: Vec<Annotation>,
 }
@@ -83,37 +125,106 @@ fn default_signal_arg_direction() -> Direction {
     Direction::Out
 }
 
+/// An annotation on some part of a D-Bus interface, such as
+/// `org.freedesktop.DBus.Deprecated`.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Annotation {
+    /// The name of the annotation.
     pub name: String,
+    /// The value of the annotation.
     pub value: String,
 }
 
+/// The direction of a method argument.
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq)]
 pub enum Direction {
+    /// An input argument, passed by the caller.
     #[serde(rename = "in")]
     In,
+    /// An output argument, returned to the caller.
     #[serde(rename = "out")]
     Out,
 }
 
+/// Whether a property can be read and written.
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq)]
 pub enum Access {
+    /// The property can be both read and written.
     #[serde(rename = "readwrite")]
     ReadWrite,
+    /// The property can only be read.
     #[serde(rename = "read")]
     Read,
+    /// The property can only be written.
     #[serde(rename = "write")]
     Write,
 }
 
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.name.as_deref().unwrap_or("."))?;
+        for interface in &self.interfaces {
+            writeln!(f, "  interface {}", interface.name)?;
+            for method in &interface.methods {
+                let args = method
+                    .args
+                    .iter()
+                    .map(|arg| {
+                        let direction = match arg.direction {
+                            Direction::In => "in",
+                            Direction::Out => "out",
+                        };
+                        format_arg(direction, arg.name.as_deref(), &arg.dbustype)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                writeln!(f, "    method {}({})", method.name, args)?;
+            }
+            for signal in &interface.signals {
+                let args = signal
+                    .args
+                    .iter()
+                    .map(|arg| format_arg("", arg.name.as_deref(), &arg.dbustype))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                writeln!(f, "    signal {}({})", signal.name, args)?;
+            }
+            for property in &interface.properties {
+                let access = match property.access {
+                    Access::ReadWrite => "readwrite",
+                    Access::Read => "read",
+                    Access::Write => "write",
+                };
+                writeln!(
+                    f,
+                    "    property {}: {} ({})",
+                    property.name, property.dbustype, access
+                )?;
+            }
+        }
+        for node in &self.nodes {
+            writeln!(f, "  node {}", node.name.as_deref().unwrap_or_default())?;
+        }
+        Ok(())
+    }
+}
+
+fn format_arg(direction: &str, name: Option<&str>, dbustype: &str) -> String {
+    [direction, dbustype, name.unwrap_or_default()]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Extension trait to introspect D-Bus objects and parse the resulting XML into a typed structure.
 pub trait IntrospectParse {
-    async fn introspect_parse(&self) -> Result<Node, BluetoothError>;
+    /// Introspect this object, and parse the resulting XML into a typed structure.
+    fn introspect_parse(&self) -> impl Future<Output = Result<Node, BluetoothError>> + Send;
 }
 
 impl<T: Introspectable + Sync> IntrospectParse for T {
-    /// Introspect this object, and parse the resulting XML into a typed structure.
     async fn introspect_parse(&self) -> Result<Node, BluetoothError> {
         let introspection_xml: String = self.introspect().await?;
         let device_node: Node = serde_xml_rs::from_str(&introspection_xml)?;
@@ -125,6 +236,33 @@ impl<T: Introspectable + Sync> IntrospectParse for T {
 mod tests {
     use super::*;
 
+    #[test]
+    fn display() {
+        let node: Node = serde_xml_rs::from_str(
+            r#"<node>
+                <interface name="org.bluez.Device1">
+                    <method name="ConnectProfile">
+                        <arg name="UUID" type="s" direction="in"/>
+                    </method>
+                    <property name="Address" type="s" access="read"/>
+                    <property name="Trusted" type="b" access="readwrite"/>
+                </interface>
+                <node name="service000a"/>
+            </node>"#,
+        )
+        .unwrap();
+        assert_eq!(
+            node.to_string(),
+            ".
+  interface org.bluez.Device1
+    method ConnectProfile(in s UUID)
+    property Address: s (read)
+    property Trusted: b (readwrite)
+  node service000a
+"
+        );
+    }
+
     #[test]
     fn introspect_root() {
         let value: Node = serde_xml_rs::from_str(
@@ -8,29 +8,55 @@
 //! [`BluetoothSession']: struct.BluetoothSession.html
 
 mod adapter;
+mod agent;
+mod batch;
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench;
 mod bleuuid;
+mod broadcast;
 mod characteristic;
 mod descriptor;
 mod device;
+mod eventfilter;
 mod events;
-mod introspect;
+pub mod introspect;
 mod macaddress;
 mod messagestream;
 mod modalias;
+mod objectpath;
+mod presentation;
 mod serde_path;
 mod service;
+mod sessionguard;
 
 pub use self::adapter::{AdapterId, AdapterInfo};
+use self::agent::authorize_pairing;
+pub use self::agent::{
+    Agent, AgentCapability, AgentRequest, AuthorizationRequest, PairingProgress,
+};
+pub use self::batch::gatt_batch;
 pub use self::bleuuid::{uuid_from_u16, uuid_from_u32, BleUuid};
+pub use self::broadcast::EventBroadcaster;
+use self::characteristic::{characteristic_ids, find_characteristic_by_uuid};
 pub use self::characteristic::{CharacteristicFlags, CharacteristicId, CharacteristicInfo};
 pub use self::descriptor::{DescriptorId, DescriptorInfo};
-pub use self::device::{AddressType, DeviceId, DeviceInfo};
+pub use self::device::{
+    convert_manufacturer_data, convert_service_data, convert_uuids, AddressType, AdvertisingFlags,
+    DeviceId, DeviceIdentity, DeviceInfo,
+};
+use self::device::{devices_from_managed_objects, DeviceFilter};
+pub use self::eventfilter::{EventFilter, EventKinds};
 pub use self::events::{AdapterEvent, BluetoothEvent, CharacteristicEvent, DeviceEvent};
-use self::introspect::IntrospectParse;
+use self::introspect::{IntrospectParse, Node};
 pub use self::macaddress::{MacAddress, ParseMacAddressError};
-use self::messagestream::MessageStream;
+use self::messagestream::{MessageStream, Subscriptions};
 pub use self::modalias::{Modalias, ParseModaliasError};
+pub use self::objectpath::ParseIdError;
+pub use self::presentation::{CharacteristicPresentation, PresentationFormat, ValueFormat};
+use self::presentation::{PRESENTATION_FORMAT_UUID, USER_DESCRIPTION_UUID};
 pub use self::service::{ServiceId, ServiceInfo};
+pub use self::sessionguard::SessionGuard;
 use bluez_generated::{
     OrgBluezAdapter1, OrgBluezAdapter1Properties, OrgBluezDevice1, OrgBluezDevice1Properties,
     OrgBluezGattCharacteristic1, OrgBluezGattCharacteristic1Properties, OrgBluezGattDescriptor1,
@@ -38,19 +64,23 @@ use bluez_generated::{
     ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME,
 };
 use dbus::arg::{PropMap, Variant};
+use dbus::message::MatchRule;
 use dbus::nonblock::stdintf::org_freedesktop_dbus::{Introspectable, ObjectManager, Properties};
 use dbus::nonblock::{Proxy, SyncConnection};
-use dbus::Path;
+use dbus::Message;
 use dbus_tokio::connection::IOResourceError;
+use futures::channel::mpsc;
+use futures::future::{self, Either};
 use futures::stream::{self, select_all, StreamExt};
 use futures::{FutureExt, Stream};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
-use tokio::task::JoinError;
+use tokio::task::{JoinError, JoinHandle};
 use tokio::time::timeout;
 use uuid::Uuid;
 
@@ -59,6 +89,8 @@ const DBUS_METHOD_CALL_TIMEOUT: Duration = Duration::from_secs(30);
 // 0x7fffffff (the largest 32-bit signed integer) or INT32_MAX
 const DBUS_METHOD_CALL_MAX_TIMEOUT: Duration = Duration::from_secs(i32::MAX as u64);
 const SERVICE_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(5);
+const ERROR_IN_PROGRESS: &str = "org.bluez.Error.InProgress";
+const BONDING_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// An error carrying out a Bluetooth operation.
 #[derive(Debug, Error)]
@@ -78,6 +110,9 @@ pub enum BluetoothError {
     /// Error parsing a UUID from a string.
     #[error("Error parsing UUID string: {0}")]
     UuidParseError(#[from] uuid::Error),
+    /// Error parsing the value of a Characteristic Presentation Format descriptor.
+    #[error("Invalid presentation format descriptor value {0:?}")]
+    PresentationFormatParseError(Vec<u8>),
     /// Error parsing a characteristic flag from a string.
     #[error("Invalid characteristic flag {0:?}")]
     FlagParseError(String),
@@ -266,6 +301,7 @@ impl From<WriteOptions> for PropMap {
 #[derive(Clone)]
 pub struct BluetoothSession {
     connection: Arc<SyncConnection>,
+    subscriptions: Subscriptions,
 }
 
 impl Debug for BluetoothSession {
@@ -280,8 +316,29 @@ impl BluetoothSession {
     /// Returns a tuple of (join handle, Self).
     /// If the join handle ever completes then you're in trouble and should
     /// probably restart the process.
+    ///
+    /// See [`new_guarded`](Self::new_guarded) for an alternative which makes the lifetime of the
+    /// background task explicit.
     pub async fn new(
     ) -> Result<(impl Future<Output = Result<(), SpawnError>>, Self), BluetoothError> {
+        let (dbus_handle, session) = Self::connect_dbus()?;
+        Ok((
+            dbus_handle.map(|res| Err(SpawnError::DbusConnectionLost(res?))),
+            session,
+        ))
+    }
+
+    /// Establish a new D-Bus connection to communicate with BlueZ, returning a [`SessionGuard`]
+    /// which owns the background task driving the connection.
+    ///
+    /// The session stops working when the guard is dropped, so keep it alive for as long as the
+    /// session is used. [`SessionGuard::closed`] completes if the connection is lost.
+    pub async fn new_guarded() -> Result<(SessionGuard, Self), BluetoothError> {
+        let (dbus_handle, session) = Self::connect_dbus()?;
+        Ok((SessionGuard::new(dbus_handle), session))
+    }
+
+    fn connect_dbus() -> Result<(JoinHandle<IOResourceError>, Self), BluetoothError> {
         // Connect to the D-Bus system bus (this is blocking, unfortunately).
         let (dbus_resource, connection) = dbus_tokio::connection::new_system_sync()?;
         // Configure the connection to send signal messages to all matching `MsgMatch`es, as we may
@@ -289,11 +346,14 @@ impl BluetoothSession {
         connection.set_signal_match_mode(true);
         // The resource is a task that should be spawned onto a tokio compatible
         // reactor ASAP. If the resource ever finishes, you lost connection to D-Bus.
-        let dbus_handle = tokio::spawn(async {
-            let err = dbus_resource.await;
-            Err(SpawnError::DbusConnectionLost(err))
-        });
-        Ok((dbus_handle.map(|res| res?), BluetoothSession { connection }))
+        let dbus_handle = tokio::spawn(dbus_resource);
+        Ok((
+            dbus_handle,
+            BluetoothSession {
+                connection,
+                subscriptions: Subscriptions::default(),
+            },
+        ))
     }
 
     /// Power on all Bluetooth adapters, remove any discovery filter, and then start scanning for
@@ -352,6 +412,14 @@ impl BluetoothSession {
     /// clients on the system using Bluetooth as well.
     ///
     /// In most common cases, `DiscoveryFilter::default()` is fine.
+    ///
+    /// BlueZ keeps track of discovery separately for each client, so this doesn't conflict with
+    /// other processes scanning on the same adapter. If this session (or a clone of it) is already
+    /// discovering on the adapter then the new filter replaces the old one and discovery continues,
+    /// rather than returning an error. Either way a single call to
+    /// [`stop_discovery_on_adapter`](Self::stop_discovery_on_adapter) stops it. If the adapter
+    /// couldn't start scanning, such as because the controller is busy, then the
+    /// `org.bluez.Error.InProgress` error from BlueZ is returned.
     pub async fn start_discovery_on_adapter_with_filter(
         &self,
         adapter_id: &AdapterId,
@@ -362,8 +430,20 @@ impl BluetoothSession {
         adapter
             .set_discovery_filter(discovery_filter.into())
             .await?;
-        adapter.start_discovery().await?;
-        Ok(())
+        match adapter.start_discovery().await {
+            // BlueZ returns this both if this D-Bus connection already has a discovery session on
+            // the adapter, and if the controller failed to start scanning, such as because it was
+            // busy. Only in the first case is the adapter actually discovering.
+            Err(e) if e.name() == Some(ERROR_IN_PROGRESS) => {
+                if adapter.discovering().await? {
+                    log::debug!("Discovery already started on {}.", adapter_id);
+                    Ok(())
+                } else {
+                    Err(e.into())
+                }
+            }
+            result => Ok(result?),
+        }
     }
 
     /// Stop scanning for devices on all Bluetooth adapters.
@@ -411,35 +491,71 @@ impl BluetoothSession {
     }
 
     /// Get a list of all Bluetooth devices which have been discovered so far.
+    ///
+    /// The returned `DeviceInfo`s are fully populated from a single `GetManagedObjects` call to
+    /// BlueZ, so there is no need to call `get_device_info` for each of them.
     pub async fn get_devices(&self) -> Result<Vec<DeviceInfo>, BluetoothError> {
-        let bluez_root = Proxy::new(
-            "org.bluez",
-            "/",
-            DBUS_METHOD_CALL_TIMEOUT,
-            self.connection.clone(),
-        );
-        let tree = bluez_root.get_managed_objects().await?;
-
-        let devices = tree
-            .into_iter()
-            .filter_map(|(object_path, interfaces)| {
-                let device_properties = OrgBluezDevice1Properties::from_interfaces(&interfaces)?;
-                DeviceInfo::from_properties(DeviceId { object_path }, device_properties).ok()
-            })
-            .collect();
-        Ok(devices)
+        self.get_devices_filtered(DeviceFilter::default()).await
     }
 
     /// Get a list of all Bluetooth devices which have been discovered so far on a given adapter.
+    ///
+    /// Like `get_devices`, this only makes a single D-Bus call, and skips parsing the properties
+    /// of devices on other adapters.
     pub async fn get_devices_on_adapter(
         &self,
         adapter: &AdapterId,
     ) -> Result<Vec<DeviceInfo>, BluetoothError> {
-        let devices = self.get_devices().await?;
-        Ok(devices
-            .into_iter()
-            .filter(|device| device.id.adapter() == *adapter)
-            .collect())
+        self.get_devices_filtered(DeviceFilter {
+            adapter: Some(adapter),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Get a list of all Bluetooth devices which are currently connected.
+    pub async fn get_connected_devices(&self) -> Result<Vec<DeviceInfo>, BluetoothError> {
+        self.get_devices_filtered(DeviceFilter {
+            connected: true,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Get a list of all Bluetooth devices which are currently paired.
+    pub async fn get_paired_devices(&self) -> Result<Vec<DeviceInfo>, BluetoothError> {
+        self.get_devices_filtered(DeviceFilter {
+            paired: true,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Get a list of all Bluetooth devices which have been discovered so far and include the given
+    /// GATT service UUID in their advertisement or resolved services.
+    pub async fn get_devices_advertising_service(
+        &self,
+        service_uuid: Uuid,
+    ) -> Result<Vec<DeviceInfo>, BluetoothError> {
+        self.get_devices_filtered(DeviceFilter {
+            service: Some(service_uuid),
+            ..Default::default()
+        })
+        .await
+    }
+
+    async fn get_devices_filtered(
+        &self,
+        filter: DeviceFilter<'_>,
+    ) -> Result<Vec<DeviceInfo>, BluetoothError> {
+        let bluez_root = Proxy::new(
+            "org.bluez",
+            "/",
+            DBUS_METHOD_CALL_TIMEOUT,
+            self.connection.clone(),
+        );
+        let tree = bluez_root.get_managed_objects().await?;
+        Ok(devices_from_managed_objects(tree, filter))
     }
 
     /// Get a list of all GATT services which the given Bluetooth device offers.
@@ -448,6 +564,16 @@ impl BluetoothSession {
     pub async fn get_services(
         &self,
         device: &DeviceId,
+    ) -> Result<Vec<ServiceInfo>, BluetoothError> {
+        self.get_services_filtered(device, &[]).await
+    }
+
+    /// Get the GATT services offered by the given device with any of the given UUIDs, or all of
+    /// them if `service_uuids` is empty.
+    async fn get_services_filtered(
+        &self,
+        device: &DeviceId,
+        service_uuids: &[Uuid],
     ) -> Result<Vec<ServiceInfo>, BluetoothError> {
         let device_node = self
             .device(device, DBUS_METHOD_CALL_TIMEOUT)
@@ -464,6 +590,9 @@ impl BluetoothSession {
                 };
                 let service = self.service(&service_id);
                 let uuid = Uuid::parse_str(&service.uuid().await?)?;
+                if !service_uuids.is_empty() && !service_uuids.contains(&uuid) {
+                    continue;
+                }
                 let primary = service.primary().await?;
                 services.push(ServiceInfo {
                     id: service_id,
@@ -482,16 +611,8 @@ impl BluetoothSession {
     ) -> Result<Vec<CharacteristicInfo>, BluetoothError> {
         let service_node = self.service(service).introspect_parse().await?;
         let mut characteristics = vec![];
-        for subnode in service_node.nodes {
-            let subnode_name = subnode.name.as_ref().unwrap();
-            // Characteristic paths are always of the form
-            // /org/bluez/{hci0,hci1,...}/dev_XX_XX_XX_XX_XX_XX/serviceXXXX/charYYYY
-            if subnode_name.starts_with("char") {
-                let characteristic_id = CharacteristicId {
-                    object_path: format!("{}/{}", service.object_path, subnode_name).into(),
-                };
-                characteristics.push(self.get_characteristic_info(&characteristic_id).await?);
-            }
+        for characteristic_id in characteristic_ids(service, &service_node) {
+            characteristics.push(self.get_characteristic_info(&characteristic_id).await?);
         }
         Ok(characteristics)
     }
@@ -547,10 +668,7 @@ impl BluetoothSession {
         uuid: Uuid,
     ) -> Result<CharacteristicInfo, BluetoothError> {
         let characteristics = self.get_characteristics(service).await?;
-        characteristics
-            .into_iter()
-            .find(|characteristic_info| characteristic_info.uuid == uuid)
-            .ok_or(BluetoothError::UuidNotFound { uuid })
+        find_characteristic_by_uuid(characteristics, uuid)
     }
 
     /// Convenience method to get a GATT charactacteristic with the given UUID advertised by a
@@ -568,6 +686,18 @@ impl BluetoothSession {
             .await
     }
 
+    /// Find the current ID of the device with the given identity, if BlueZ knows about it.
+    ///
+    /// This should be called again to get an up to date `DeviceId` whenever the device might have
+    /// changed address, such as before reconnecting to it.
+    pub async fn resolve_device(
+        &self,
+        identity: &DeviceIdentity,
+    ) -> Result<Option<DeviceId>, BluetoothError> {
+        let devices = self.get_devices_on_adapter(&identity.adapter).await?;
+        Ok(identity.find(&devices))
+    }
+
     /// Get information about the given Bluetooth device.
     pub async fn get_device_info(&self, id: &DeviceId) -> Result<DeviceInfo, BluetoothError> {
         let device = self.device(id, DBUS_METHOD_CALL_TIMEOUT);
@@ -621,6 +751,42 @@ impl BluetoothSession {
         })
     }
 
+    /// Introspect the D-Bus object for the given adapter, to see which interfaces, methods and
+    /// properties BlueZ provides for it. This is mainly useful for debugging.
+    pub async fn introspect_adapter(&self, id: &AdapterId) -> Result<Node, BluetoothError> {
+        self.adapter(id).introspect_parse().await
+    }
+
+    /// Introspect the D-Bus object for the given device, to see which interfaces, methods and
+    /// properties BlueZ provides for it. This is mainly useful for debugging.
+    ///
+    /// The returned [`Node`] can be printed with `Display` to get a readable summary.
+    pub async fn introspect_device(&self, id: &DeviceId) -> Result<Node, BluetoothError> {
+        self.device(id, DBUS_METHOD_CALL_TIMEOUT)
+            .introspect_parse()
+            .await
+    }
+
+    /// Read and parse the Characteristic Presentation Format and Characteristic User Description
+    /// descriptors of the given GATT characteristic, to find out how its value should be
+    /// displayed. Either may be `None` if the characteristic doesn't have that descriptor.
+    pub async fn get_characteristic_presentation(
+        &self,
+        id: &CharacteristicId,
+    ) -> Result<CharacteristicPresentation, BluetoothError> {
+        let mut presentation = CharacteristicPresentation::default();
+        for descriptor in self.get_descriptors(id).await? {
+            if descriptor.uuid == PRESENTATION_FORMAT_UUID {
+                let value = self.read_descriptor_value(&descriptor.id).await?;
+                presentation.format = Some(PresentationFormat::try_from(value.as_slice())?);
+            } else if descriptor.uuid == USER_DESCRIPTION_UUID {
+                let value = self.read_descriptor_value(&descriptor.id).await?;
+                presentation.user_description = Some(String::from_utf8_lossy(&value).into_owned());
+            }
+        }
+        Ok(presentation)
+    }
+
     fn adapter(&self, id: &AdapterId) -> impl OrgBluezAdapter1 + Introspectable + Properties {
         Proxy::new(
             "org.bluez",
@@ -707,6 +873,48 @@ impl BluetoothSession {
         .unwrap_or(Err(BluetoothError::ServiceDiscoveryTimedOut))
     }
 
+    /// Wait until the given device is connected. This completes immediately if it is already
+    /// connected.
+    ///
+    /// There is no timeout, so wrap this in [`tokio::time::timeout`] if needed.
+    pub async fn wait_for_connect(&self, id: &DeviceId) -> Result<(), BluetoothError> {
+        self.await_connected(id, true).await
+    }
+
+    /// Wait until the given device is disconnected, such as because the link was lost. This
+    /// completes immediately if it is already disconnected.
+    ///
+    /// This is useful for supervisory tasks to `select!` on alongside their other work.
+    pub async fn wait_for_disconnect(&self, id: &DeviceId) -> Result<(), BluetoothError> {
+        self.await_connected(id, false).await
+    }
+
+    /// Wait until the `Connected` property of the given device has the given value.
+    async fn await_connected(&self, id: &DeviceId, connected: bool) -> Result<(), BluetoothError> {
+        // We need to subscribe to events before checking current value to avoid a race condition.
+        let mut events = self.device_event_stream(id).await?;
+        if self
+            .device(id, DBUS_METHOD_CALL_TIMEOUT)
+            .connected()
+            .await?
+            == connected
+        {
+            return Ok(());
+        }
+        while let Some(event) = events.next().await {
+            if matches!(event, BluetoothEvent::Device {
+                id: event_id,
+                event: DeviceEvent::Connected { connected: event_connected },
+            } if id == &event_id && event_connected == connected)
+            {
+                return Ok(());
+            }
+        }
+
+        // Stream ended prematurely. This shouldn't happen, so something has gone wrong.
+        Err(dbus::Error::new_failed("Event stream ended unexpectedly").into())
+    }
+
     /// Connect to the given Bluetooth device.
     pub async fn connect(&self, id: &DeviceId) -> Result<(), BluetoothError> {
         self.connect_with_timeout(id, DBUS_METHOD_CALL_TIMEOUT)
@@ -723,6 +931,26 @@ impl BluetoothSession {
         self.await_service_discovery(id).await
     }
 
+    /// Connect to the given Bluetooth device, and get the GATT services it offers with any of the
+    /// given UUIDs, ignoring the rest. If `service_uuids` is empty then all services are returned,
+    /// as with [`get_services`](Self::get_services).
+    ///
+    /// The filter is applied here rather than by BlueZ, which doesn't support limiting service
+    /// discovery to particular services. So this still waits for BlueZ to discover all services
+    /// and their characteristics and descriptors, and still reads the `UUID` property of every
+    /// service to check it against the filter. It only skips reading the other properties of
+    /// services which don't match, and as they aren't returned, the caller won't go on to query
+    /// their characteristics and descriptors either. This can save time on devices with large
+    /// GATT tables.
+    pub async fn connect_with_service_filter(
+        &self,
+        id: &DeviceId,
+        service_uuids: &[Uuid],
+    ) -> Result<Vec<ServiceInfo>, BluetoothError> {
+        self.connect(id).await?;
+        self.get_services_filtered(id, service_uuids).await
+    }
+
     /// Disconnect from the given Bluetooth device.
     pub async fn disconnect(&self, id: &DeviceId) -> Result<(), BluetoothError> {
         Ok(self
@@ -731,6 +959,119 @@ impl BluetoothSession {
             .await?)
     }
 
+    /// Pair with the given device if it isn't already paired, mark it as trusted, and wait for the
+    /// pairing to be bonded. The returned stream reports each step as it is completed, ending
+    /// after the last step or the first error.
+    ///
+    /// Not all pairings are bonded, such as LE pairing with bonding disabled, so the pairing
+    /// succeeds once the device is paired and trusted. If it is then bonded within a few seconds
+    /// the stream reports [`PairingProgress::Bonded`] before it ends, otherwise it ends after
+    /// [`PairingProgress::Trusted`].
+    ///
+    /// If `register_agent` is true then a temporary agent with no input or output capabilities
+    /// is registered for the duration of the pairing. It accepts 'just works' pairing requests
+    /// from the device and rejects everything else. Otherwise the pairing relies on an agent
+    /// which has already been registered, such as with [`register_agent`](Self::register_agent).
+    pub fn pair_and_trust(
+        &self,
+        id: &DeviceId,
+        register_agent: bool,
+    ) -> impl Stream<Item = Result<PairingProgress, BluetoothError>> {
+        let (sender, receiver) = mpsc::unbounded();
+        let session = self.clone();
+        let id = id.to_owned();
+        let pairing = async move {
+            let progress = |step| {
+                // The receiver is only dropped if the stream is, in which case this future is too.
+                let _ = sender.unbounded_send(Ok(step));
+            };
+            if let Err(e) = session
+                .pair_and_trust_steps(&id, register_agent, progress)
+                .await
+            {
+                let _ = sender.unbounded_send(Err(e));
+            }
+        };
+        // Drive the pairing as part of polling the stream, rather than spawning a task for it.
+        stream::select(
+            receiver,
+            pairing.into_stream().filter_map(|()| future::ready(None)),
+        )
+    }
+
+    async fn pair_and_trust_steps(
+        &self,
+        id: &DeviceId,
+        register_agent: bool,
+        progress: impl Fn(PairingProgress),
+    ) -> Result<(), BluetoothError> {
+        // Pairing may be waiting on the user, so don't time out the method call.
+        let device = self.device(id, DBUS_METHOD_CALL_MAX_TIMEOUT);
+        // We need to subscribe to events before checking current value to avoid a race condition.
+        let mut events = self.device_event_stream(id).await?;
+
+        if !device.paired().await? {
+            let agent = if register_agent {
+                let agent = self
+                    .register_agent(AgentCapability::NoInputNoOutput, false)
+                    .await?;
+                progress(PairingProgress::AgentRegistered);
+                Some(agent)
+            } else {
+                None
+            };
+            progress(PairingProgress::PairingStarted);
+            match agent {
+                Some(mut agent) => {
+                    let authorize = Box::pin(authorize_pairing(&mut agent, id));
+                    match future::select(device.pair(), authorize).await {
+                        Either::Left((result, _)) => result?,
+                        // The agent has stopped receiving requests, but the pairing may still
+                        // succeed without it.
+                        Either::Right(((), pair)) => pair.await?,
+                    }
+                    // Dropping the agent unregisters it.
+                }
+                None => device.pair().await?,
+            }
+        }
+        progress(PairingProgress::Paired);
+
+        device.set_trusted(true).await?;
+        progress(PairingProgress::Trusted);
+
+        // Older versions of BlueZ don't have the Bonded property, and always bond when pairing.
+        let bonded = match device.bonded().await {
+            Ok(bonded) => bonded,
+            Err(e) => {
+                log::info!("Couldn't get Bonded property ({}), assuming bonded.", e);
+                true
+            }
+        };
+        let bonded = bonded
+            || timeout(BONDING_TIMEOUT, async {
+                while let Some(event) = events.next().await {
+                    if matches!(event, BluetoothEvent::Device {
+                        id: event_id,
+                        event: DeviceEvent::Bonded { bonded: true },
+                    } if id == &event_id)
+                    {
+                        return true;
+                    }
+                }
+                // The stream ended prematurely, so the bonding can't be observed.
+                false
+            })
+            .await
+            .unwrap_or(false);
+        if bonded {
+            progress(PairingProgress::Bonded);
+        } else {
+            log::info!("{} is paired and trusted but not bonded.", id);
+        }
+        Ok(())
+    }
+
     /// Read the value of the given GATT characteristic.
     ///
     /// This is equivalent to calling `read_characteristic_value_with_offset(0)`.
@@ -820,6 +1161,32 @@ impl BluetoothSession {
             .await?)
     }
 
+    /// Read the values of all readable characteristics on the given GATT service, with at most
+    /// `max_concurrent` reads in progress at once.
+    ///
+    /// Returns each readable characteristic along with the result of reading it, so a failure to
+    /// read one characteristic doesn't prevent the others from being read.
+    pub async fn read_all_characteristics(
+        &self,
+        service: &ServiceId,
+        max_concurrent: usize,
+    ) -> Result<Vec<(CharacteristicInfo, Result<Vec<u8>, BluetoothError>)>, BluetoothError> {
+        let characteristics: Vec<CharacteristicInfo> = self
+            .get_characteristics(service)
+            .await?
+            .into_iter()
+            .filter(|characteristic| characteristic.flags.contains(CharacteristicFlags::READ))
+            .collect();
+        let values = gatt_batch(
+            characteristics
+                .iter()
+                .map(|characteristic| self.read_characteristic_value(&characteristic.id)),
+            max_concurrent,
+        )
+        .await;
+        Ok(characteristics.into_iter().zip(values).collect())
+    }
+
     /// Start notifications on the given GATT characteristic.
     pub async fn start_notify(&self, id: &CharacteristicId) -> Result<(), BluetoothError> {
         let characteristic = self.characteristic(id);
@@ -834,18 +1201,111 @@ impl BluetoothSession {
         Ok(())
     }
 
+    /// Periodically check that a connected device is still responding, by reading the given
+    /// characteristic every `interval`.
+    ///
+    /// The returned stream yields a `DeviceEvent::Unresponsive` event for the device if a read
+    /// fails or takes longer than `timeout` while BlueZ still reports the device as connected,
+    /// which can happen when the link silently dies. Further failures aren't reported until a read
+    /// has succeeded again. Nothing is read while the device is disconnected.
+    ///
+    /// The characteristic should be cheap to read and not have any side effects. Reading the RSSI
+    /// isn't enough for this, as BlueZ answers that from its cache without talking to the device.
+    /// The watchdog stops when the stream is dropped.
+    pub fn device_watchdog(
+        &self,
+        characteristic: &CharacteristicId,
+        interval: Duration,
+        timeout: Duration,
+    ) -> impl Stream<Item = BluetoothEvent> {
+        let session = self.clone();
+        stream::unfold(
+            (session, characteristic.to_owned(), true),
+            move |(session, characteristic, mut responsive)| async move {
+                let device = characteristic.device();
+                loop {
+                    tokio::time::sleep(interval).await;
+                    let connected = session
+                        .device(&device, DBUS_METHOD_CALL_TIMEOUT)
+                        .connected()
+                        .await
+                        .unwrap_or(false);
+                    if !connected {
+                        responsive = true;
+                        continue;
+                    }
+                    let read = tokio::time::timeout(
+                        timeout,
+                        session.read_characteristic_value(&characteristic),
+                    )
+                    .await;
+                    match read {
+                        Ok(Ok(_)) => responsive = true,
+                        Ok(Err(e)) if responsive => {
+                            log::warn!("Liveness ping to {} failed: {}", device, e);
+                            break;
+                        }
+                        Err(_) if responsive => {
+                            log::warn!("Liveness ping to {} timed out", device);
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                let event = BluetoothEvent::Device {
+                    id: device,
+                    event: DeviceEvent::Unresponsive,
+                };
+                Some((event, (session, characteristic, false)))
+            },
+        )
+    }
+
+    /// Get a stream of the events which match the given filter.
+    ///
+    /// Only the D-Bus match rules needed for the filter are added, and they are removed as soon as
+    /// the stream is dropped; see [`active_subscriptions`](Self::active_subscriptions).
+    pub async fn events(
+        &self,
+        filter: EventFilter,
+    ) -> Result<impl Stream<Item = BluetoothEvent>, BluetoothError> {
+        let messages = self.filtered_message_stream(filter.match_rules()).await?;
+        let devices = if filter.has_service_uuid() {
+            filter.initial_devices(&self.get_devices().await?)
+        } else {
+            HashSet::new()
+        };
+        Ok(filter.filter_messages(messages, devices))
+    }
+
+    /// Subscribe to the events which match the given filter, and share them between any number
+    /// of subscribers as `Arc<BluetoothEvent>`s.
+    ///
+    /// This is cheaper than calling [`events`](Self::events) for each subscriber when there are
+    /// many of them, as each event is only parsed and stored once.
+    pub async fn broadcast_events(
+        &self,
+        filter: EventFilter,
+    ) -> Result<EventBroadcaster, BluetoothError> {
+        Ok(EventBroadcaster::new(self.events(filter).await?))
+    }
+
     /// Get a stream of events for all devices.
+    ///
+    /// This is equivalent to `events(EventFilter::new())`.
     pub async fn event_stream(&self) -> Result<impl Stream<Item = BluetoothEvent>, BluetoothError> {
-        self.filtered_event_stream(None::<&DeviceId>, true).await
+        self.events(EventFilter::new()).await
     }
 
     /// Get a stream of events for a particular adapter. This includes events for all devices it
     /// discovers or connects to.
+    ///
+    /// This is equivalent to `events(EventFilter::new().adapter(adapter))`.
     pub async fn adapter_event_stream(
         &self,
         adapter: &AdapterId,
     ) -> Result<impl Stream<Item = BluetoothEvent>, BluetoothError> {
-        self.filtered_event_stream(Some(adapter), true).await
+        self.events(EventFilter::new().adapter(adapter)).await
     }
 
     /// Get a stream of events for a particular device. This includes events for all its
@@ -853,34 +1313,66 @@ impl BluetoothSession {
     ///
     /// Note that this will not include the device discovered event for that device, as it is
     /// considered an event for the adapter rather than the device itself.
+    ///
+    /// This is equivalent to `events(EventFilter::new().device(device))`.
     pub async fn device_event_stream(
         &self,
         device: &DeviceId,
     ) -> Result<impl Stream<Item = BluetoothEvent>, BluetoothError> {
-        self.filtered_event_stream(Some(device), false).await
+        self.events(EventFilter::new().device(device)).await
     }
 
     /// Get a stream of events for a particular characteristic of a device.
+    ///
+    /// This is equivalent to `events(EventFilter::new().characteristic(characteristic))`.
     pub async fn characteristic_event_stream(
         &self,
         characteristic: &CharacteristicId,
     ) -> Result<impl Stream<Item = BluetoothEvent>, BluetoothError> {
-        self.filtered_event_stream(Some(characteristic), false)
+        self.events(EventFilter::new().characteristic(characteristic))
             .await
     }
 
-    async fn filtered_event_stream(
+    /// Register an agent with BlueZ to handle authorization requests, such as for pairing or for
+    /// remote devices connecting to services on this host.
+    ///
+    /// The returned [`Agent`] is a stream of requests which the application should accept or
+    /// reject, or provide a passkey or PIN code for. If `default` is true then the agent will also
+    /// be made the default agent for the system, so that it handles requests which weren't
+    /// initiated by this application. The agent is unregistered when it is dropped.
+    pub async fn register_agent(
         &self,
-        object: Option<&(impl Into<Path<'static>> + Clone)>,
-        device_discovery: bool,
-    ) -> Result<impl Stream<Item = BluetoothEvent>, BluetoothError> {
+        capability: AgentCapability,
+        default: bool,
+    ) -> Result<Agent, BluetoothError> {
+        Agent::register(self.connection.clone(), capability, default).await
+    }
+
+    /// Get the D-Bus match rules currently registered by event streams on this session, for
+    /// diagnostic purposes.
+    ///
+    /// Each event stream holds one or more match rules, which are removed when the stream is
+    /// dropped. If this keeps growing then some streams are probably being leaked.
+    pub fn active_subscriptions(&self) -> Vec<String> {
+        self.subscriptions.match_rules()
+    }
+
+    async fn filtered_message_stream(
+        &self,
+        match_rules: Vec<MatchRule<'static>>,
+    ) -> Result<impl Stream<Item = Message>, BluetoothError> {
         let mut message_streams = vec![];
-        for match_rule in BluetoothEvent::match_rules(object.cloned(), device_discovery) {
+        for match_rule in match_rules {
+            let match_str = match_rule.match_str();
             let msg_match = self.connection.add_match(match_rule).await?;
-            message_streams.push(MessageStream::new(msg_match, self.connection.clone()));
+            message_streams.push(MessageStream::new(
+                msg_match,
+                match_str,
+                self.connection.clone(),
+                self.subscriptions.clone(),
+            ));
         }
-        Ok(select_all(message_streams)
-            .flat_map(|message| stream::iter(BluetoothEvent::message_to_events(message))))
+        Ok(select_all(message_streams))
     }
 }
 
@@ -1,10 +1,35 @@
+use dbus::channel::{MatchingReceiver, Token};
 use dbus::nonblock::{MsgMatch, SyncConnection};
 use dbus::Message;
 use futures::channel::mpsc::UnboundedReceiver;
 use futures::Stream;
+use std::collections::HashMap;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
+use tokio::runtime::Handle;
+
+/// The set of D-Bus match rules currently held by `MessageStream`s for a session, keyed by the
+/// token of their `MsgMatch`.
+#[derive(Clone, Default)]
+pub struct Subscriptions(Arc<Mutex<HashMap<Token, String>>>);
+
+impl Subscriptions {
+    fn insert(&self, token: Token, match_str: String) {
+        self.0.lock().unwrap().insert(token, match_str);
+    }
+
+    fn remove(&self, token: Token) {
+        self.0.lock().unwrap().remove(&token);
+    }
+
+    /// Get the match rule strings of all current subscriptions, in a consistent order.
+    pub fn match_rules(&self) -> Vec<String> {
+        let mut match_rules: Vec<String> = self.0.lock().unwrap().values().cloned().collect();
+        match_rules.sort();
+        match_rules
+    }
+}
 
 /// Wrapper for a stream of D-Bus messages which automatically removes the `MsgMatch` from the D-Bus
 /// connection when it is dropped.
@@ -12,15 +37,23 @@ pub struct MessageStream {
     msg_match: Option<MsgMatch>,
     events: UnboundedReceiver<Message>,
     connection: Arc<SyncConnection>,
+    subscriptions: Subscriptions,
 }
 
 impl MessageStream {
-    pub fn new(msg_match: MsgMatch, connection: Arc<SyncConnection>) -> Self {
+    pub fn new(
+        msg_match: MsgMatch,
+        match_str: String,
+        connection: Arc<SyncConnection>,
+        subscriptions: Subscriptions,
+    ) -> Self {
         let (msg_match, events) = msg_match.msg_stream();
+        subscriptions.insert(msg_match.token(), match_str);
         Self {
             msg_match: Some(msg_match),
             events,
             connection,
+            subscriptions,
         }
     }
 }
@@ -35,8 +68,52 @@ impl Stream for MessageStream {
 
 impl Drop for MessageStream {
     fn drop(&mut self) {
-        let connection = self.connection.clone();
-        let msg_match = self.msg_match.take().unwrap();
-        tokio::spawn(async move { connection.remove_match(msg_match.token()).await.unwrap() });
+        let token = self.msg_match.take().unwrap().token();
+        self.subscriptions.remove(token);
+        // Stop dispatching messages to this stream straight away, so that only the call to remove
+        // the match rule from the bus needs to happen asynchronously.
+        if let Some((match_rule, _)) = self.connection.stop_receive(token) {
+            let match_str = match_rule.match_str();
+            let connection = self.connection.clone();
+            match Handle::try_current() {
+                Ok(handle) => {
+                    handle.spawn(async move {
+                        if let Err(e) = connection.remove_match_no_cb(&match_str).await {
+                            log::warn!("Failed to remove match rule {}: {}", match_str, e);
+                        }
+                    });
+                }
+                Err(_) => log::warn!(
+                    "No tokio runtime available to remove match rule {}",
+                    match_str
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscriptions_insert_remove() {
+        let subscriptions = Subscriptions::default();
+        subscriptions.insert(Token(2), "type='signal',member='b'".to_string());
+        subscriptions.insert(Token(1), "type='signal',member='a'".to_string());
+        assert_eq!(
+            subscriptions.match_rules(),
+            vec!["type='signal',member='a'", "type='signal',member='b'"]
+        );
+
+        subscriptions.remove(Token(1));
+        assert_eq!(
+            subscriptions.match_rules(),
+            vec!["type='signal',member='b'"]
+        );
+
+        // Clones share the same set.
+        subscriptions.clone().remove(Token(2));
+        assert!(subscriptions.match_rules().is_empty());
     }
 }
@@ -0,0 +1,135 @@
+use thiserror::Error;
+
+use crate::MacAddress;
+
+const BLUEZ_PATH_PREFIX: &str = "/org/bluez/";
+
+/// An error parsing an adapter, device, service, characteristic or descriptor ID from a D-Bus
+/// object path or string.
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[error("Invalid {kind} ID '{path}'")]
+pub struct ParseIdError {
+    kind: &'static str,
+    path: String,
+}
+
+impl ParseIdError {
+    pub(crate) fn new(kind: &'static str, path: &str) -> Self {
+        Self {
+            kind,
+            path: path.to_owned(),
+        }
+    }
+}
+
+/// Check whether the given string is a valid BlueZ adapter object path, e.g. `/org/bluez/hci0`.
+pub(crate) fn is_adapter_path(path: &str) -> bool {
+    match path.strip_prefix(BLUEZ_PATH_PREFIX) {
+        Some(name) => !name.is_empty() && !name.contains('/'),
+        None => false,
+    }
+}
+
+/// Check whether the given string is a valid BlueZ device object path, e.g.
+/// `/org/bluez/hci0/dev_11_22_33_44_55_66`.
+pub(crate) fn is_device_path(path: &str) -> bool {
+    match path.rsplit_once('/') {
+        Some((parent, name)) => is_adapter_path(parent) && mac_address_from_node(name).is_some(),
+        None => false,
+    }
+}
+
+/// Check whether the given string is a valid BlueZ GATT service object path, e.g.
+/// `/org/bluez/hci0/dev_11_22_33_44_55_66/service0012`.
+pub(crate) fn is_service_path(path: &str) -> bool {
+    is_handle_path(path, "service", is_device_path)
+}
+
+/// Check whether the given string is a valid BlueZ GATT characteristic object path, e.g.
+/// `/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034`.
+pub(crate) fn is_characteristic_path(path: &str) -> bool {
+    is_handle_path(path, "char", is_service_path)
+}
+
+/// Check whether the given string is a valid BlueZ GATT descriptor object path, e.g.
+/// `/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034/desc0056`.
+pub(crate) fn is_descriptor_path(path: &str) -> bool {
+    is_handle_path(path, "desc", is_characteristic_path)
+}
+
+/// Check whether the last node of the given path is the given prefix followed by a 4 digit hex
+/// handle, and the rest is a valid parent path.
+fn is_handle_path(path: &str, prefix: &str, is_parent_path: fn(&str) -> bool) -> bool {
+    match path.rsplit_once('/') {
+        Some((parent, name)) => {
+            is_parent_path(parent)
+                && name.strip_prefix(prefix).is_some_and(|handle| {
+                    handle.len() == 4 && handle.chars().all(|c| c.is_ascii_hexdigit())
+                })
+        }
+        None => false,
+    }
+}
+
+/// Parse the MAC address from a device node name like `dev_11_22_33_44_55_66`.
+pub(crate) fn mac_address_from_node(name: &str) -> Option<MacAddress> {
+    name.strip_prefix("dev_")?.replace('_', ":").parse().ok()
+}
+
+/// Convert a string in the format used by the `Display` implementations of IDs back to a full
+/// object path.
+pub(crate) fn path_from_display(s: &str) -> String {
+    format!("{}{}", BLUEZ_PATH_PREFIX, s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapter_path() {
+        assert!(is_adapter_path("/org/bluez/hci0"));
+        assert!(!is_adapter_path("/org/bluez/"));
+        assert!(!is_adapter_path("/org/bluez"));
+        assert!(!is_adapter_path("/org/bluez/hci0/dev_11_22_33_44_55_66"));
+        assert!(!is_adapter_path("/com/example/hci0"));
+    }
+
+    #[test]
+    fn device_path() {
+        assert!(is_device_path("/org/bluez/hci0/dev_11_22_33_44_55_66"));
+        assert!(!is_device_path("/org/bluez/hci0"));
+        assert!(!is_device_path("/org/bluez/hci0/dev_11_22_33_44_55"));
+        assert!(!is_device_path("/org/bluez/hci0/11_22_33_44_55_66"));
+        assert!(!is_device_path("/org/bluez/dev_11_22_33_44_55_66"));
+    }
+
+    #[test]
+    fn gatt_paths() {
+        let service = "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012";
+        let characteristic = "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char00ab";
+        let descriptor = "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char00ab/desc0056";
+        assert!(is_service_path(service));
+        assert!(is_characteristic_path(characteristic));
+        assert!(is_descriptor_path(descriptor));
+
+        assert!(!is_service_path(characteristic));
+        assert!(!is_characteristic_path(service));
+        assert!(!is_descriptor_path(characteristic));
+        assert!(!is_service_path(
+            "/org/bluez/hci0/dev_11_22_33_44_55_66/service12"
+        ));
+        assert!(!is_characteristic_path(
+            "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/charxyzw"
+        ));
+    }
+
+    #[test]
+    fn mac_address_from_node_name() {
+        assert_eq!(
+            mac_address_from_node("dev_11_22_33_44_55_AA"),
+            Some("11:22:33:44:55:AA".parse().unwrap())
+        );
+        assert_eq!(mac_address_from_node("service0012"), None);
+    }
+}
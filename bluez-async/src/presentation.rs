@@ -0,0 +1,170 @@
+use std::convert::TryFrom;
+use uuid::Uuid;
+
+use crate::{uuid_from_u16, BluetoothError};
+
+/// The UUID of the Characteristic User Description descriptor.
+pub(crate) const USER_DESCRIPTION_UUID: Uuid = uuid_from_u16(0x2901);
+/// The UUID of the Characteristic Presentation Format descriptor.
+pub(crate) const PRESENTATION_FORMAT_UUID: Uuid = uuid_from_u16(0x2904);
+
+/// The format of a characteristic value, from a Characteristic Presentation Format descriptor.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ValueFormat {
+    Boolean,
+    UInt2,
+    UInt4,
+    UInt8,
+    UInt12,
+    UInt16,
+    UInt24,
+    UInt32,
+    UInt48,
+    UInt64,
+    UInt128,
+    SInt8,
+    SInt12,
+    SInt16,
+    SInt24,
+    SInt32,
+    SInt48,
+    SInt64,
+    SInt128,
+    /// IEEE-754 32-bit floating point.
+    Float32,
+    /// IEEE-754 64-bit floating point.
+    Float64,
+    /// IEEE-11073 16-bit SFLOAT.
+    SFloat,
+    /// IEEE-11073 32-bit FLOAT.
+    Float,
+    /// IEEE-20601 format, two unsigned 16-bit integers.
+    DUInt16,
+    /// UTF-8 string.
+    Utf8,
+    /// UTF-16 string.
+    Utf16,
+    /// Opaque structure.
+    Struct,
+    /// A format value which is reserved or not known to this crate.
+    Other(u8),
+}
+
+impl From<u8> for ValueFormat {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Boolean,
+            0x02 => Self::UInt2,
+            0x03 => Self::UInt4,
+            0x04 => Self::UInt8,
+            0x05 => Self::UInt12,
+            0x06 => Self::UInt16,
+            0x07 => Self::UInt24,
+            0x08 => Self::UInt32,
+            0x09 => Self::UInt48,
+            0x0a => Self::UInt64,
+            0x0b => Self::UInt128,
+            0x0c => Self::SInt8,
+            0x0d => Self::SInt12,
+            0x0e => Self::SInt16,
+            0x0f => Self::SInt24,
+            0x10 => Self::SInt32,
+            0x11 => Self::SInt48,
+            0x12 => Self::SInt64,
+            0x13 => Self::SInt128,
+            0x14 => Self::Float32,
+            0x15 => Self::Float64,
+            0x16 => Self::SFloat,
+            0x17 => Self::Float,
+            0x18 => Self::DUInt16,
+            0x19 => Self::Utf8,
+            0x1a => Self::Utf16,
+            0x1b => Self::Struct,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+/// The parsed value of a Characteristic Presentation Format descriptor (UUID 0x2904), which
+/// describes how to interpret and display the value of the characteristic it is on.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PresentationFormat {
+    /// The format of the characteristic value.
+    pub format: ValueFormat,
+    /// The base 10 exponent to multiply integer values by to get the actual value.
+    pub exponent: i8,
+    /// The unit of the value, as a Bluetooth SIG assigned number such as 0x272F for degrees
+    /// Celsius.
+    pub unit: u16,
+    /// The organization which assigns description values. 1 is the Bluetooth SIG.
+    pub namespace: u8,
+    /// A description of the value, whose meaning depends on the namespace.
+    pub description: u16,
+}
+
+impl TryFrom<&[u8]> for PresentationFormat {
+    type Error = BluetoothError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if let [format, exponent, unit_low, unit_high, namespace, description_low, description_high] =
+            *value
+        {
+            Ok(Self {
+                format: format.into(),
+                exponent: exponent as i8,
+                unit: u16::from_le_bytes([unit_low, unit_high]),
+                namespace,
+                description: u16::from_le_bytes([description_low, description_high]),
+            })
+        } else {
+            Err(BluetoothError::PresentationFormatParseError(
+                value.to_owned(),
+            ))
+        }
+    }
+}
+
+/// Information about how to present the value of a GATT characteristic, from its descriptors.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CharacteristicPresentation {
+    /// The parsed Characteristic Presentation Format descriptor, if the characteristic has one.
+    pub format: Option<PresentationFormat>,
+    /// The Characteristic User Description, if the characteristic has one. This is a
+    /// human-readable name for the characteristic.
+    pub user_description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_presentation_format() {
+        // Temperature in units of 0.01 degrees Celsius.
+        let value: &[u8] = &[0x0e, 0xfe, 0x2f, 0x27, 0x01, 0x00, 0x00];
+        assert_eq!(
+            PresentationFormat::try_from(value).unwrap(),
+            PresentationFormat {
+                format: ValueFormat::SInt16,
+                exponent: -2,
+                unit: 0x272f,
+                namespace: 1,
+                description: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_presentation_format_invalid() {
+        let value: &[u8] = &[0x0e, 0xfe, 0x2f, 0x27, 0x01, 0x00];
+        assert!(matches!(
+            PresentationFormat::try_from(value),
+            Err(BluetoothError::PresentationFormatParseError(_))
+        ));
+        let value: &[u8] = &[0xff, 0, 0, 0, 0, 0, 0];
+        assert_eq!(
+            PresentationFormat::try_from(value).unwrap().format,
+            ValueFormat::Other(0xff)
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use dbus::Path;
 use serde::de::Error as _;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
 use std::ops::Deref;
 
+use crate::ParseIdError;
+
 /// Serialize a [`Path`] as a string.
 pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
     path.deref().serialize(serializer)
@@ -11,5 +14,24 @@ pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::
 /// Deserialize a [`Path`] from a string.
 pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Path<'static>, D::Error> {
     let string = String::deserialize(deserializer)?;
-    Ok(Path::new(string).map_err(|e| D::Error::custom(format!("Invalid D-Bus path: {:?}", e)))?)
+    Path::new(string).map_err(|e| D::Error::custom(format!("Invalid D-Bus path: {:?}", e)))
+}
+
+/// The serialized form of an adapter, device, service, characteristic or descriptor ID.
+///
+/// IDs are deserialized via this and then converted with their `TryFrom<Path>` implementation, so
+/// that the object path is validated.
+#[derive(Deserialize)]
+pub(crate) struct SerializedId {
+    #[serde(with = "self")]
+    object_path: Path<'static>,
+}
+
+impl SerializedId {
+    /// Convert to the given type of ID, checking that the object path is valid for it.
+    pub(crate) fn try_into_id<T: TryFrom<Path<'static>, Error = ParseIdError>>(
+        self,
+    ) -> Result<T, ParseIdError> {
+        T::try_from(self.object_path)
+    }
 }
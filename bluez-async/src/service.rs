@@ -1,12 +1,17 @@
 use dbus::Path;
 use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 use uuid::Uuid;
 
-use crate::DeviceId;
+use crate::objectpath::{is_service_path, path_from_display};
+use crate::serde_path::SerializedId;
+use crate::{AdapterId, DeviceId, ParseIdError};
 
 /// Opaque identifier for a GATT service on a Bluetooth device.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "SerializedId")]
 pub struct ServiceId {
     #[serde(with = "crate::serde_path")]
     pub(crate) object_path: Path<'static>,
@@ -27,6 +32,18 @@ impl ServiceId {
             .expect("ServiceId object_path must contain a slash.");
         DeviceId::new(&self.object_path[0..index])
     }
+
+    /// Get the ID of the Bluetooth adapter on which the device offering this service was
+    /// discovered.
+    pub fn adapter(&self) -> AdapterId {
+        self.device().adapter()
+    }
+
+    /// Get the D-Bus object path of the service, e.g.
+    /// `"/org/bluez/hci0/dev_11_22_33_44_55_66/service0022"`.
+    pub fn object_path(&self) -> &str {
+        &self.object_path
+    }
 }
 
 impl From<ServiceId> for Path<'static> {
@@ -35,6 +52,39 @@ impl From<ServiceId> for Path<'static> {
     }
 }
 
+impl TryFrom<Path<'static>> for ServiceId {
+    type Error = ParseIdError;
+
+    fn try_from(object_path: Path<'static>) -> Result<Self, Self::Error> {
+        if is_service_path(&object_path) {
+            Ok(Self { object_path })
+        } else {
+            Err(ParseIdError::new("service", &object_path))
+        }
+    }
+}
+
+impl TryFrom<SerializedId> for ServiceId {
+    type Error = ParseIdError;
+
+    fn try_from(id: SerializedId) -> Result<Self, Self::Error> {
+        id.try_into_id()
+    }
+}
+
+impl FromStr for ServiceId {
+    type Err = ParseIdError;
+
+    /// Parse a service ID in the format produced by its `Display` implementation, e.g.
+    /// `"hci0/dev_11_22_33_44_55_66/service0022"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::new(path_from_display(s))
+            .ok()
+            .and_then(|object_path| object_path.try_into().ok())
+            .ok_or_else(|| ParseIdError::new("service", s))
+    }
+}
+
 impl Display for ServiceId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -79,4 +129,17 @@ mod tests {
             "hci0/dev_11_22_33_44_55_66/service0022"
         );
     }
+
+    #[test]
+    fn service_adapter() {
+        let service_id = ServiceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0022");
+        assert_eq!(service_id.adapter(), AdapterId::new("/org/bluez/hci0"));
+    }
+
+    #[test]
+    fn from_str() {
+        let service_id = ServiceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/service0022");
+        assert_eq!(service_id.to_string().parse(), Ok(service_id));
+        assert!("hci0/dev_11_22_33_44_55_66".parse::<ServiceId>().is_err());
+    }
 }
@@ -0,0 +1,54 @@
+use dbus_tokio::connection::IOResourceError;
+use futures::future;
+use std::fmt::{self, Debug, Formatter};
+use tokio::task::JoinHandle;
+
+use crate::SpawnError;
+
+/// Owns the background task which drives the D-Bus connection of a
+/// [`BluetoothSession`](crate::BluetoothSession), as returned by
+/// [`BluetoothSession::new_guarded`](crate::BluetoothSession::new_guarded).
+///
+/// The task is aborted when the guard is dropped, after which the session will stop working. Use
+/// [`closed`](Self::closed) to find out if the connection is lost while the guard is alive.
+pub struct SessionGuard {
+    task: Option<JoinHandle<IOResourceError>>,
+}
+
+impl SessionGuard {
+    pub(crate) fn new(task: JoinHandle<IOResourceError>) -> Self {
+        Self { task: Some(task) }
+    }
+
+    /// Wait until the D-Bus connection is lost, returning the reason.
+    ///
+    /// This is cancellation safe, so can be used in a `select!` loop. Once it has returned, later
+    /// calls will never complete.
+    pub async fn closed(&mut self) -> SpawnError {
+        let result = match &mut self.task {
+            Some(task) => task.await,
+            None => future::pending().await,
+        };
+        self.task = None;
+        match result {
+            Ok(e) => SpawnError::DbusConnectionLost(e),
+            Err(e) => SpawnError::Join(e),
+        }
+    }
+}
+
+impl Debug for SessionGuard {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SessionGuard")
+            .field("running", &self.task.is_some())
+            .finish()
+    }
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
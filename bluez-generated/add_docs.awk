@@ -0,0 +1,98 @@
+# Merges documentation from a docs/*.txt file into code generated by dbus-codegen-rust.
+#
+# Usage: awk -f add_docs.awk docs/org.bluez.Example1.txt src/example1.rs
+#
+# Each entry in the docs file is a line with an interface, method or property name at the start,
+# followed by the documentation for it indented by 4 spaces. The entry for the interface name is
+# added to the trait, and each method or property entry to the corresponding trait method and
+# property getter. Lines starting with '#' are ignored.
+
+# Convert a D-Bus name to a Rust function name, in the same way as dbus-codegen-rust.
+function snake(name,    result, lower, i, c) {
+    result = ""
+    lower = 0
+    for (i = 1; i <= length(name); i++) {
+        c = substr(name, i, 1)
+        if (c ~ /[a-z0-9]/) {
+            result = result c
+            lower = 1
+        } else if (c ~ /[A-Z]/) {
+            if (lower) {
+                result = result "_"
+            }
+            lower = 0
+            result = result tolower(c)
+        } else {
+            if (lower) {
+                result = result "_"
+            }
+            lower = 0
+        }
+    }
+    if (length(result) < 2 || result ~ /^(as|break|const|continue|crate|else|enum|extern|false|fn|for|if|impl|in|let|loop|match|mod|move|mut|pub|ref|return|self|static|struct|super|trait|true|type|unsafe|use|where|while|async|await|dyn|try)$/) {
+        result = result "_"
+    }
+    return result
+}
+
+function print_doc(key, indent,    n, lines, i) {
+    n = split(docs[key], lines, "\n")
+    while (n > 1 && lines[n - 1] == "") {
+        n--
+    }
+    for (i = 1; i < n; i++) {
+        if (lines[i] == "") {
+            print indent "///"
+        } else {
+            print indent "/// " lines[i]
+        }
+    }
+}
+
+# Read the docs file.
+FNR == NR {
+    if ($0 ~ /^#/) {
+        next
+    }
+    if ($0 ~ /^[^ ]/) {
+        key = $1
+        if (interface == "") {
+            interface = key
+        } else {
+            key = snake(key)
+        }
+        docs[key] = ""
+    } else if (key != "") {
+        line = $0
+        sub(/^    /, "", line)
+        sub(/^ *$/, "", line)
+        docs[key] = docs[key] line "\n"
+    }
+    next
+}
+
+# Merge the docs into the generated code.
+/^pub trait / {
+    if (interface in docs) {
+        print_doc(interface, "")
+    }
+    in_trait = 1
+}
+/^impl<'a> .*Properties<'a> \{$/ {
+    in_properties = 1
+}
+/^}$/ {
+    in_trait = 0
+    in_properties = 0
+}
+(in_trait && match($0, /^    fn [a-z0-9_]+\(/)) || (in_properties && match($0, /^    pub fn [a-z0-9_]+\(/)) {
+    name = $0
+    sub(/^ *(pub )?fn /, "", name)
+    sub(/\(.*/, "", name)
+    if (name in docs) {
+        print_doc(name, "    ")
+    }
+}
+{
+    print
+}
@@ -11,6 +11,7 @@
 #
 # Code generation requires dbus-codegen-rust.
 # Install with `cargo install dbus-codegen`.
+# Documentation from docs/*.txt is merged into the generated code by add_docs.awk.
 ## Set GENERATE=0 to skip code generation.
 
 set -euo pipefail
@@ -63,7 +64,9 @@ fi
 
 if [ "$GENERATE" = 1 ]; then
     echo "// Generated by introspect.sh" > src/lib.rs
-    echo "#![allow(clippy::upper_case_acronyms, clippy::needless_borrow)]" >> src/lib.rs
+    echo "#![allow(clippy::upper_case_acronyms, clippy::needless_borrow, clippy::too_many_arguments, clippy::type_complexity)]" >> src/lib.rs
+    # Accessors for the typed property views of each interface, for BluezObject.
+    object_accessors=''
     for file in specs/org.bluez.*.xml; do
         interface=$(
             echo "$file" \
@@ -72,8 +75,15 @@ if [ "$GENERATE" = 1 ]; then
         modname=$(
             echo "$interface" \
                 | sed -e 's/^org.bluez.//' \
-                | tr '[:upper:]' '[:lower:]'
+                | tr '[:upper:].' '[:lower:]_'
         )
+        # The OBEX and mesh daemons' interfaces are optional, so put them behind features. They
+        # aren't served by bluetoothd, so their specs aren't introspected above.
+        case "$interface" in
+            org.bluez.obex.*) cfg='#[cfg(feature = "obex")] ' ;;
+            org.bluez.mesh.*) cfg='#[cfg(feature = "mesh")] ' ;;
+            *) cfg='' ;;
+        esac
         dbus-codegen-rust \
             --file="$file" \
             --interfaces="$interface" \
@@ -83,8 +93,56 @@ if [ "$GENERATE" = 1 ]; then
             | grep -v '^use dbus as dbus;$' \
             | rustfmt \
             > "src/$modname.rs"
-        echo "pub mod $modname;" >> src/lib.rs
-        echo "pub use $modname::*;" >> src/lib.rs
+        if [ -f "docs/$interface.txt" ]; then
+            awk -f add_docs.awk "docs/$interface.txt" "src/$modname.rs" > "src/$modname.rs.tmp"
+            mv "src/$modname.rs.tmp" "src/$modname.rs"
+        fi
+        echo "${cfg}pub mod $modname;" >> src/lib.rs
+        echo "${cfg}pub use $modname::*;" >> src/lib.rs
+        properties=$(sed -n -e "s/^pub struct \(.*Properties\)<'a>.*/\1/p" "src/$modname.rs")
+        if [ -n "$properties" ]; then
+            object_accessors+="
+    /// Get the \`$interface\` properties of the object, if it implements that interface.
+    ${cfg}pub fn $modname(&self) -> Option<$properties<'_>> {
+        $properties::from_interfaces(&self.interfaces)
+    }
+"
+        fi
     done
+    cat > src/objects.rs <<EOF
+// Generated by introspect.sh
+use crate::*;
+use dbus::arg;
+use dbus::nonblock;
+use dbus::nonblock::stdintf::org_freedesktop_dbus::ObjectManager;
+use std::collections::HashMap;
+
+/// An object returned by \`GetManagedObjects\`, with typed views of the properties of the
+/// interfaces it implements.
+#[derive(Debug, Default)]
+pub struct BluezObject {
+    /// The properties of each interface which the object implements, keyed by interface name.
+    pub interfaces: HashMap<String, arg::PropMap>,
+}
+
+impl BluezObject {$object_accessors}
+
+/// Call \`GetManagedObjects\` on the given \`org.freedesktop.DBus.ObjectManager\` proxy, and wrap
+/// each object in a \`BluezObject\`.
+pub async fn get_managed_objects<T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>>(
+    proxy: &nonblock::Proxy<'_, C>,
+) -> Result<HashMap<dbus::Path<'static>, BluezObject>, dbus::Error> {
+    let tree = proxy.get_managed_objects().await?;
+    Ok(tree
+        .into_iter()
+        .map(|(path, interfaces)| (path, BluezObject { interfaces }))
+        .collect())
+}
+EOF
+    echo "pub mod objects;" >> src/lib.rs
+    echo "pub use objects::*;" >> src/lib.rs
+    # Hand-written helpers, which aren't generated.
+    echo "pub mod advertisement_data;" >> src/lib.rs
+    echo "pub use advertisement_data::*;" >> src/lib.rs
     cargo fmt
 fi
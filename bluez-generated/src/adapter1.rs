@@ -3,33 +3,62 @@
 use dbus::arg;
 use dbus::nonblock;
 
+/// A local Bluetooth adapter (controller), at an object path like `/org/bluez/hci0`.
 pub trait OrgBluezAdapter1 {
+    /// Starts device discovery. This also clears the list of discovered devices which are not
+    /// connected.
+    ///
+    /// Discovery continues until `StopDiscovery` is called by every client which started it, or
+    /// the clients disconnect from D-Bus.
     fn start_discovery(&self) -> nonblock::MethodReply<()>;
+    /// Sets the filter used for device discovery by this client, which is merged with the filters
+    /// of other clients. Supported keys include `UUIDs`, `RSSI`, `Pathloss`, `Transport`,
+    /// `DuplicateData`, `Discoverable` and `Pattern`.
     fn set_discovery_filter(&self, properties: arg::PropMap) -> nonblock::MethodReply<()>;
+    /// Stops device discovery started by this client.
     fn stop_discovery(&self) -> nonblock::MethodReply<()>;
+    /// Removes the given remote device and its pairing information.
     fn remove_device(&self, device: dbus::Path) -> nonblock::MethodReply<()>;
+    /// Returns the keys which are supported by `SetDiscoveryFilter`.
     fn get_discovery_filters(&self) -> nonblock::MethodReply<Vec<String>>;
+    /// The Bluetooth address of the adapter.
     fn address(&self) -> nonblock::MethodReply<String>;
+    /// The type of the adapter's address, either `public` or `random`.
     fn address_type(&self) -> nonblock::MethodReply<String>;
+    /// The Bluetooth system name, usually the hostname.
     fn name(&self) -> nonblock::MethodReply<String>;
+    /// The Bluetooth friendly name. This defaults to the system name, and setting it to an empty
+    /// string resets it to the default.
     fn alias(&self) -> nonblock::MethodReply<String>;
     fn set_alias(&self, value: String) -> nonblock::MethodReply<()>;
+    /// The Bluetooth class of device.
     fn class(&self) -> nonblock::MethodReply<u32>;
+    /// Whether the adapter is switched on.
     fn powered(&self) -> nonblock::MethodReply<bool>;
     fn set_powered(&self, value: bool) -> nonblock::MethodReply<()>;
     fn power_state(&self) -> nonblock::MethodReply<String>;
+    /// Whether the adapter is discoverable by other devices.
     fn discoverable(&self) -> nonblock::MethodReply<bool>;
     fn set_discoverable(&self, value: bool) -> nonblock::MethodReply<()>;
+    /// How long in seconds the adapter stays discoverable for, or 0 to stay discoverable forever.
     fn discoverable_timeout(&self) -> nonblock::MethodReply<u32>;
     fn set_discoverable_timeout(&self, value: u32) -> nonblock::MethodReply<()>;
+    /// Whether the adapter accepts new pairings.
     fn pairable(&self) -> nonblock::MethodReply<bool>;
     fn set_pairable(&self, value: bool) -> nonblock::MethodReply<()>;
+    /// How long in seconds the adapter stays pairable for, or 0 to stay pairable forever.
     fn pairable_timeout(&self) -> nonblock::MethodReply<u32>;
     fn set_pairable_timeout(&self, value: u32) -> nonblock::MethodReply<()>;
+    /// Whether device discovery is in progress.
     fn discovering(&self) -> nonblock::MethodReply<bool>;
+    /// The UUIDs of the services which are available locally.
     fn uuids(&self) -> nonblock::MethodReply<Vec<String>>;
+    /// Identifies the Bluetooth stack, in the format used by the Device ID profile.
     fn modalias(&self) -> nonblock::MethodReply<String>;
+    /// The LE roles which the adapter supports, such as `central`, `peripheral` and
+    /// `central-peripheral`.
     fn roles(&self) -> nonblock::MethodReply<Vec<String>>;
+    /// The UUIDs of the experimental features which are currently enabled.
     fn experimental_features(&self) -> nonblock::MethodReply<Vec<String>>;
 }
 
@@ -45,26 +74,33 @@ impl<'a> OrgBluezAdapter1Properties<'a> {
         interfaces.get("org.bluez.Adapter1").map(Self)
     }
 
+    /// The Bluetooth address of the adapter.
     pub fn address(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Address")
     }
 
+    /// The type of the adapter's address, either `public` or `random`.
     pub fn address_type(&self) -> Option<&String> {
         arg::prop_cast(self.0, "AddressType")
     }
 
+    /// The Bluetooth system name, usually the hostname.
     pub fn name(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Name")
     }
 
+    /// The Bluetooth friendly name. This defaults to the system name, and setting it to an empty
+    /// string resets it to the default.
     pub fn alias(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Alias")
     }
 
+    /// The Bluetooth class of device.
     pub fn class(&self) -> Option<u32> {
         arg::prop_cast(self.0, "Class").copied()
     }
 
+    /// Whether the adapter is switched on.
     pub fn powered(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Powered").copied()
     }
@@ -73,38 +109,48 @@ impl<'a> OrgBluezAdapter1Properties<'a> {
         arg::prop_cast(self.0, "PowerState")
     }
 
+    /// Whether the adapter is discoverable by other devices.
     pub fn discoverable(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Discoverable").copied()
     }
 
+    /// How long in seconds the adapter stays discoverable for, or 0 to stay discoverable forever.
     pub fn discoverable_timeout(&self) -> Option<u32> {
         arg::prop_cast(self.0, "DiscoverableTimeout").copied()
     }
 
+    /// Whether the adapter accepts new pairings.
     pub fn pairable(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Pairable").copied()
     }
 
+    /// How long in seconds the adapter stays pairable for, or 0 to stay pairable forever.
     pub fn pairable_timeout(&self) -> Option<u32> {
         arg::prop_cast(self.0, "PairableTimeout").copied()
     }
 
+    /// Whether device discovery is in progress.
     pub fn discovering(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Discovering").copied()
     }
 
+    /// The UUIDs of the services which are available locally.
     pub fn uuids(&self) -> Option<&Vec<String>> {
         arg::prop_cast(self.0, "UUIDs")
     }
 
+    /// Identifies the Bluetooth stack, in the format used by the Device ID profile.
     pub fn modalias(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Modalias")
     }
 
+    /// The LE roles which the adapter supports, such as `central`, `peripheral` and
+    /// `central-peripheral`.
     pub fn roles(&self) -> Option<&Vec<String>> {
         arg::prop_cast(self.0, "Roles")
     }
 
+    /// The UUIDs of the experimental features which are currently enabled.
     pub fn experimental_features(&self) -> Option<&Vec<String>> {
         arg::prop_cast(self.0, "ExperimentalFeatures")
     }
@@ -0,0 +1,18 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.AdminPolicySet1.xml --interfaces=org.bluez.AdminPolicySet1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezAdminPolicySet1 {
+    fn set_service_allow_list(&self, uuids: Vec<&str>) -> nonblock::MethodReply<()>;
+}
+
+pub const ORG_BLUEZ_ADMIN_POLICY_SET1_NAME: &str = "org.bluez.AdminPolicySet1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezAdminPolicySet1
+    for nonblock::Proxy<'a, C>
+{
+    fn set_service_allow_list(&self, uuids: Vec<&str>) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.AdminPolicySet1", "SetServiceAllowList", (uuids,))
+    }
+}
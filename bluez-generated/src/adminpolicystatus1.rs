@@ -0,0 +1,50 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.AdminPolicyStatus1.xml --interfaces=org.bluez.AdminPolicyStatus1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezAdminPolicyStatus1 {
+    fn service_allow_list(&self) -> nonblock::MethodReply<Vec<String>>;
+    fn is_affected_by_policy(&self) -> nonblock::MethodReply<bool>;
+}
+
+pub const ORG_BLUEZ_ADMIN_POLICY_STATUS1_NAME: &str = "org.bluez.AdminPolicyStatus1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezAdminPolicyStatus1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezAdminPolicyStatus1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.AdminPolicyStatus1").map(Self)
+    }
+
+    pub fn service_allow_list(&self) -> Option<&Vec<String>> {
+        arg::prop_cast(self.0, "ServiceAllowList")
+    }
+
+    pub fn is_affected_by_policy(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "IsAffectedByPolicy").copied()
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezAdminPolicyStatus1
+    for nonblock::Proxy<'a, C>
+{
+    fn service_allow_list(&self) -> nonblock::MethodReply<Vec<String>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdminPolicyStatus1",
+            "ServiceAllowList",
+        )
+    }
+
+    fn is_affected_by_policy(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdminPolicyStatus1",
+            "IsAffectedByPolicy",
+        )
+    }
+}
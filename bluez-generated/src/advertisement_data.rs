@@ -0,0 +1,186 @@
+//! Helpers for building the properties of an `org.bluez.LEAdvertisement1` object exported by an
+//! application. This module is written by hand rather than generated.
+
+use dbus::arg::{PropMap, RefArg, Variant};
+use std::collections::HashMap;
+
+/// The properties of an advertisement to register with `LEAdvertisingManager1`, with the
+/// complex D-Bus property types replaced by simple Rust types.
+///
+/// Use [`to_prop_map`](Self::to_prop_map) to get the value to return from `GetAll` for the
+/// `org.bluez.LEAdvertisement1` interface. Properties which are `None` or empty are left out.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LEAdvertisementProperties {
+    /// The advertisement type, either `"broadcast"` or `"peripheral"`. BlueZ requires this to be
+    /// set.
+    pub advertisement_type: String,
+    /// The UUIDs of services to include in the advertisement.
+    pub service_uuids: Vec<String>,
+    /// Manufacturer-specific data, keyed by company ID.
+    pub manufacturer_data: HashMap<u16, Vec<u8>>,
+    /// The UUIDs of services to include in the service solicitation.
+    pub solicit_uuids: Vec<String>,
+    /// Service data, keyed by service UUID.
+    pub service_data: HashMap<String, Vec<u8>>,
+    /// Raw advertising data, keyed by AD type.
+    pub data: HashMap<u8, Vec<u8>>,
+    /// Whether to advertise as general discoverable.
+    pub discoverable: Option<bool>,
+    /// How long in seconds to stay discoverable for.
+    pub discoverable_timeout: Option<u16>,
+    /// Extra information for BlueZ to include, such as `"tx-power"`, `"appearance"` or
+    /// `"local-name"`.
+    pub includes: Vec<String>,
+    /// The local name to advertise.
+    pub local_name: Option<String>,
+    /// The appearance to advertise.
+    pub appearance: Option<u16>,
+    /// How long in seconds to advertise for each time the advertisement is rotated in.
+    pub duration: Option<u16>,
+    /// How long in seconds the advertisement should last for before it is removed.
+    pub timeout: Option<u16>,
+    /// The secondary channel to use for extended advertising, one of `"1M"`, `"2M"` or `"Coded"`.
+    pub secondary_channel: Option<String>,
+    /// The minimum advertising interval in milliseconds.
+    pub min_interval: Option<u32>,
+    /// The maximum advertising interval in milliseconds.
+    pub max_interval: Option<u32>,
+    /// The transmission power to advertise at, in dBm.
+    pub tx_power: Option<i16>,
+}
+
+impl LEAdvertisementProperties {
+    /// Convert the properties to the map of property names to values used by D-Bus.
+    pub fn to_prop_map(&self) -> PropMap {
+        let mut properties = PropMap::new();
+        if !self.advertisement_type.is_empty() {
+            insert(
+                &mut properties,
+                "Type",
+                Some(self.advertisement_type.clone()),
+            );
+        }
+        insert_vec(&mut properties, "ServiceUUIDs", &self.service_uuids);
+        if !self.manufacturer_data.is_empty() {
+            properties.insert(
+                "ManufacturerData".to_string(),
+                Variant(Box::new(manufacturer_data(&self.manufacturer_data))),
+            );
+        }
+        insert_vec(&mut properties, "SolicitUUIDs", &self.solicit_uuids);
+        if !self.service_data.is_empty() {
+            properties.insert(
+                "ServiceData".to_string(),
+                Variant(Box::new(service_data(&self.service_data))),
+            );
+        }
+        if !self.data.is_empty() {
+            properties.insert(
+                "Data".to_string(),
+                Variant(Box::new(advertising_data(&self.data))),
+            );
+        }
+        insert(&mut properties, "Discoverable", self.discoverable);
+        insert(
+            &mut properties,
+            "DiscoverableTimeout",
+            self.discoverable_timeout,
+        );
+        insert_vec(&mut properties, "Includes", &self.includes);
+        insert(&mut properties, "LocalName", self.local_name.clone());
+        insert(&mut properties, "Appearance", self.appearance);
+        insert(&mut properties, "Duration", self.duration);
+        insert(&mut properties, "Timeout", self.timeout);
+        insert(
+            &mut properties,
+            "SecondaryChannel",
+            self.secondary_channel.clone(),
+        );
+        insert(&mut properties, "MinInterval", self.min_interval);
+        insert(&mut properties, "MaxInterval", self.max_interval);
+        insert(&mut properties, "TxPower", self.tx_power);
+        properties
+    }
+}
+
+/// Convert manufacturer-specific data to the `a{qv}` type used by the `ManufacturerData`
+/// property.
+pub fn manufacturer_data(
+    data: &HashMap<u16, Vec<u8>>,
+) -> HashMap<u16, Variant<Box<dyn RefArg + 'static>>> {
+    data.iter()
+        .map(|(&company_id, value)| (company_id, Variant(Box::new(value.clone()) as Box<_>)))
+        .collect()
+}
+
+/// Convert service data to the `a{sv}` type used by the `ServiceData` property.
+pub fn service_data(data: &HashMap<String, Vec<u8>>) -> PropMap {
+    data.iter()
+        .map(|(uuid, value)| (uuid.clone(), Variant(Box::new(value.clone()) as Box<_>)))
+        .collect()
+}
+
+/// Convert raw advertising data to the `a{yv}` type used by the `Data` property.
+pub fn advertising_data(
+    data: &HashMap<u8, Vec<u8>>,
+) -> HashMap<u8, Variant<Box<dyn RefArg + 'static>>> {
+    data.iter()
+        .map(|(&ad_type, value)| (ad_type, Variant(Box::new(value.clone()) as Box<_>)))
+        .collect()
+}
+
+fn insert<T: RefArg + 'static>(properties: &mut PropMap, name: &str, value: Option<T>) {
+    if let Some(value) = value {
+        properties.insert(name.to_string(), Variant(Box::new(value)));
+    }
+}
+
+fn insert_vec(properties: &mut PropMap, name: &str, value: &[String]) {
+    if !value.is_empty() {
+        properties.insert(name.to_string(), Variant(Box::new(value.to_owned())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OrgBluezLEAdvertisement1Properties;
+
+    #[test]
+    fn to_prop_map() {
+        let mut manufacturer = HashMap::new();
+        manufacturer.insert(0x1234, vec![1, 2, 3]);
+        let advertisement = LEAdvertisementProperties {
+            advertisement_type: "peripheral".to_string(),
+            service_uuids: vec!["180d".to_string()],
+            manufacturer_data: manufacturer,
+            local_name: Some("Test".to_string()),
+            tx_power: Some(-4),
+            secondary_channel: Some("2M".to_string()),
+            min_interval: Some(100),
+            max_interval: Some(200),
+            ..Default::default()
+        };
+
+        let properties = advertisement.to_prop_map();
+        let view = OrgBluezLEAdvertisement1Properties(&properties);
+        assert_eq!(view.type_().unwrap(), "peripheral");
+        assert_eq!(view.service_uuids().unwrap(), &vec!["180d".to_string()]);
+        assert_eq!(view.local_name().unwrap(), "Test");
+        assert_eq!(view.tx_power(), Some(-4));
+        assert_eq!(view.secondary_channel().unwrap(), "2M");
+        assert_eq!(view.min_interval(), Some(100));
+        assert_eq!(view.max_interval(), Some(200));
+        assert_eq!(view.discoverable(), None);
+        assert_eq!(view.solicit_uuids(), None);
+        let manufacturer_data = view.manufacturer_data().unwrap();
+        assert_eq!(manufacturer_data[&0x1234].0.as_iter().unwrap().count(), 3);
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert!(LEAdvertisementProperties::default()
+            .to_prop_map()
+            .is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.AdvertisementMonitor1.xml --interfaces=org.bluez.AdvertisementMonitor1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezAdvertisementMonitor1 {
+    fn release(&self) -> nonblock::MethodReply<()>;
+    fn activate(&self) -> nonblock::MethodReply<()>;
+    fn device_found(&self, device: dbus::Path) -> nonblock::MethodReply<()>;
+    fn device_lost(&self, device: dbus::Path) -> nonblock::MethodReply<()>;
+    fn type_(&self) -> nonblock::MethodReply<String>;
+    fn rssilow_threshold(&self) -> nonblock::MethodReply<i16>;
+    fn rssihigh_threshold(&self) -> nonblock::MethodReply<i16>;
+    fn rssilow_timeout(&self) -> nonblock::MethodReply<u16>;
+    fn rssihigh_timeout(&self) -> nonblock::MethodReply<u16>;
+    fn rssisampling_period(&self) -> nonblock::MethodReply<u16>;
+    fn patterns(&self) -> nonblock::MethodReply<Vec<(u8, u8, Vec<u8>)>>;
+}
+
+pub const ORG_BLUEZ_ADVERTISEMENT_MONITOR1_NAME: &str = "org.bluez.AdvertisementMonitor1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezAdvertisementMonitor1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezAdvertisementMonitor1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.AdvertisementMonitor1").map(Self)
+    }
+
+    pub fn type_(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Type")
+    }
+
+    pub fn rssilow_threshold(&self) -> Option<i16> {
+        arg::prop_cast(self.0, "RSSILowThreshold").copied()
+    }
+
+    pub fn rssihigh_threshold(&self) -> Option<i16> {
+        arg::prop_cast(self.0, "RSSIHighThreshold").copied()
+    }
+
+    pub fn rssilow_timeout(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "RSSILowTimeout").copied()
+    }
+
+    pub fn rssihigh_timeout(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "RSSIHighTimeout").copied()
+    }
+
+    pub fn rssisampling_period(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "RSSISamplingPeriod").copied()
+    }
+
+    pub fn patterns(&self) -> Option<&Vec<(u8, u8, Vec<u8>)>> {
+        arg::prop_cast(self.0, "Patterns")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezAdvertisementMonitor1
+    for nonblock::Proxy<'a, C>
+{
+    fn release(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.AdvertisementMonitor1", "Release", ())
+    }
+
+    fn activate(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.AdvertisementMonitor1", "Activate", ())
+    }
+
+    fn device_found(&self, device: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.AdvertisementMonitor1", "DeviceFound", (device,))
+    }
+
+    fn device_lost(&self, device: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.AdvertisementMonitor1", "DeviceLost", (device,))
+    }
+
+    fn type_(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitor1",
+            "Type",
+        )
+    }
+
+    fn rssilow_threshold(&self) -> nonblock::MethodReply<i16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitor1",
+            "RSSILowThreshold",
+        )
+    }
+
+    fn rssihigh_threshold(&self) -> nonblock::MethodReply<i16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitor1",
+            "RSSIHighThreshold",
+        )
+    }
+
+    fn rssilow_timeout(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitor1",
+            "RSSILowTimeout",
+        )
+    }
+
+    fn rssihigh_timeout(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitor1",
+            "RSSIHighTimeout",
+        )
+    }
+
+    fn rssisampling_period(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitor1",
+            "RSSISamplingPeriod",
+        )
+    }
+
+    fn patterns(&self) -> nonblock::MethodReply<Vec<(u8, u8, Vec<u8>)>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitor1",
+            "Patterns",
+        )
+    }
+}
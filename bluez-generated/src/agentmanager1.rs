@@ -3,9 +3,15 @@
 use dbus::arg;
 use dbus::nonblock;
 
+/// Registers agents to handle pairing and authorization, at `/org/bluez`.
 pub trait OrgBluezAgentManager1 {
+    /// Registers an agent with the given IO capability, which is one of `DisplayOnly`,
+    /// `DisplayYesNo`, `KeyboardOnly`, `NoInputNoOutput` and `KeyboardDisplay`.
     fn register_agent(&self, agent: dbus::Path, capability: &str) -> nonblock::MethodReply<()>;
+    /// Unregisters an agent registered by `RegisterAgent`.
     fn unregister_agent(&self, agent: dbus::Path) -> nonblock::MethodReply<()>;
+    /// Makes a registered agent the default agent, which is used for requests which are not
+    /// initiated by a client with its own agent.
     fn request_default_agent(&self, agent: dbus::Path) -> nonblock::MethodReply<()>;
 }
 
@@ -3,8 +3,12 @@
 use dbus::arg;
 use dbus::nonblock;
 
+/// The battery of a remote device, on the same object as its `org.bluez.Device1` interface.
 pub trait OrgBluezBattery1 {
+    /// The battery level, from 0 to 100.
     fn percentage(&self) -> nonblock::MethodReply<u8>;
+    /// Describes where the battery information comes from, such as a GATT service or HFP.
+    fn source(&self) -> nonblock::MethodReply<String>;
 }
 
 pub const ORG_BLUEZ_BATTERY1_NAME: &str = "org.bluez.Battery1";
@@ -19,9 +23,15 @@ impl<'a> OrgBluezBattery1Properties<'a> {
         interfaces.get("org.bluez.Battery1").map(Self)
     }
 
+    /// The battery level, from 0 to 100.
     pub fn percentage(&self) -> Option<u8> {
         arg::prop_cast(self.0, "Percentage").copied()
     }
+
+    /// Describes where the battery information comes from, such as a GATT service or HFP.
+    pub fn source(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Source")
+    }
 }
 
 impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezBattery1
@@ -34,4 +44,12 @@ impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezB
             "Percentage",
         )
     }
+
+    fn source(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.Battery1",
+            "Source",
+        )
+    }
 }
@@ -0,0 +1,72 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.BatteryProvider1.xml --interfaces=org.bluez.BatteryProvider1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+/// A battery exported by an application, to tell BlueZ the battery level of a remote device which
+/// it learns about out-of-band. Battery providers are registered with
+/// `org.bluez.BatteryProviderManager1`, as objects under an object manager.
+pub trait OrgBluezBatteryProvider1 {
+    /// The object path of the device which the battery belongs to.
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    /// The battery level, from 0 to 100.
+    fn percentage(&self) -> nonblock::MethodReply<u8>;
+    /// Describes where the battery information comes from.
+    fn source(&self) -> nonblock::MethodReply<String>;
+}
+
+pub const ORG_BLUEZ_BATTERY_PROVIDER1_NAME: &str = "org.bluez.BatteryProvider1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezBatteryProvider1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezBatteryProvider1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.BatteryProvider1").map(Self)
+    }
+
+    /// The object path of the device which the battery belongs to.
+    pub fn device(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Device")
+    }
+
+    /// The battery level, from 0 to 100.
+    pub fn percentage(&self) -> Option<u8> {
+        arg::prop_cast(self.0, "Percentage").copied()
+    }
+
+    /// Describes where the battery information comes from.
+    pub fn source(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Source")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezBatteryProvider1
+    for nonblock::Proxy<'a, C>
+{
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.BatteryProvider1",
+            "Device",
+        )
+    }
+
+    fn percentage(&self) -> nonblock::MethodReply<u8> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.BatteryProvider1",
+            "Percentage",
+        )
+    }
+
+    fn source(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.BatteryProvider1",
+            "Source",
+        )
+    }
+}
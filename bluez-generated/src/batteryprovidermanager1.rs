@@ -0,0 +1,37 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.BatteryProviderManager1.xml --interfaces=org.bluez.BatteryProviderManager1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+/// Registers applications which provide battery information for remote devices, on the adapter
+/// object.
+pub trait OrgBluezBatteryProviderManager1 {
+    /// Registers a battery provider. The given object path must implement
+    /// `org.freedesktop.DBus.ObjectManager`, and each `org.bluez.BatteryProvider1` object under it
+    /// is exposed as the `org.bluez.Battery1` interface of the corresponding device.
+    fn register_battery_provider(&self, provider: dbus::Path) -> nonblock::MethodReply<()>;
+    /// Unregisters a battery provider registered by `RegisterBatteryProvider`.
+    fn unregister_battery_provider(&self, provider: dbus::Path) -> nonblock::MethodReply<()>;
+}
+
+pub const ORG_BLUEZ_BATTERY_PROVIDER_MANAGER1_NAME: &str = "org.bluez.BatteryProviderManager1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>>
+    OrgBluezBatteryProviderManager1 for nonblock::Proxy<'a, C>
+{
+    fn register_battery_provider(&self, provider: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.BatteryProviderManager1",
+            "RegisterBatteryProvider",
+            (provider,),
+        )
+    }
+
+    fn unregister_battery_provider(&self, provider: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.BatteryProviderManager1",
+            "UnregisterBatteryProvider",
+            (provider,),
+        )
+    }
+}
@@ -3,43 +3,83 @@
 use dbus::arg;
 use dbus::nonblock;
 
+/// A remote Bluetooth device, at an object path like `/org/bluez/hci0/dev_11_22_33_44_55_66`.
 pub trait OrgBluezDevice1 {
+    /// Disconnects all connected profiles, and then terminates the low-level connection.
     fn disconnect(&self) -> nonblock::MethodReply<()>;
+    /// Connects all profiles which the remote device supports and which are flagged as
+    /// auto-connectable.
     fn connect(&self) -> nonblock::MethodReply<()>;
+    /// Connects the profile with the given UUID.
     fn connect_profile(&self, uuid: &str) -> nonblock::MethodReply<()>;
+    /// Disconnects the profile with the given UUID.
     fn disconnect_profile(&self, uuid: &str) -> nonblock::MethodReply<()>;
+    /// Pairs with the remote device. Any agent is only involved if the pairing method requires it.
     fn pair(&self) -> nonblock::MethodReply<()>;
+    /// Cancels a pairing operation started by `Pair`.
     fn cancel_pairing(&self) -> nonblock::MethodReply<()>;
+    /// The Bluetooth address of the remote device.
     fn address(&self) -> nonblock::MethodReply<String>;
+    /// The type of the device's address, either `public` or `random`.
     fn address_type(&self) -> nonblock::MethodReply<String>;
+    /// The name of the remote device, if known. `Alias` should usually be used instead.
     fn name(&self) -> nonblock::MethodReply<String>;
+    /// The name to show for the remote device. This defaults to the remote name, or the address if
+    /// the name is not known, and setting it to an empty string resets it to the default.
     fn alias(&self) -> nonblock::MethodReply<String>;
     fn set_alias(&self, value: String) -> nonblock::MethodReply<()>;
+    /// The Bluetooth class of device.
     fn class(&self) -> nonblock::MethodReply<u32>;
+    /// The external appearance of the device, as advertised.
     fn appearance(&self) -> nonblock::MethodReply<u16>;
+    /// A freedesktop.org icon name for the type of device.
     fn icon(&self) -> nonblock::MethodReply<String>;
+    /// Whether the device is paired.
     fn paired(&self) -> nonblock::MethodReply<bool>;
+    /// Whether the pairing information is stored, so the device is paired after a restart.
     fn bonded(&self) -> nonblock::MethodReply<bool>;
+    /// Whether the device is trusted, so that its connections don't need to be authorized by an
+    /// agent.
     fn trusted(&self) -> nonblock::MethodReply<bool>;
     fn set_trusted(&self, value: bool) -> nonblock::MethodReply<()>;
+    /// Whether connections from the device are rejected.
     fn blocked(&self) -> nonblock::MethodReply<bool>;
     fn set_blocked(&self, value: bool) -> nonblock::MethodReply<()>;
+    /// Whether the device only supports pre-2.1 legacy pairing.
     fn legacy_pairing(&self) -> nonblock::MethodReply<bool>;
+    /// The received signal strength of the device's last inquiry result or advertisement, in dBm.
     fn rssi(&self) -> nonblock::MethodReply<i16>;
+    /// Whether the device is connected.
     fn connected(&self) -> nonblock::MethodReply<bool>;
+    /// The UUIDs of the services which the device advertises or provides.
     fn uuids(&self) -> nonblock::MethodReply<Vec<String>>;
+    /// Identifies the remote device, in the format used by the Device ID profile.
     fn modalias(&self) -> nonblock::MethodReply<String>;
+    /// The object path of the adapter which the device belongs to.
     fn adapter(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    /// Manufacturer-specific advertisement data, keyed by company ID.
     fn manufacturer_data(
         &self,
     ) -> nonblock::MethodReply<
         ::std::collections::HashMap<u16, arg::Variant<Box<dyn arg::RefArg + 'static>>>,
     >;
+    /// Service advertisement data, keyed by UUID.
     fn service_data(&self) -> nonblock::MethodReply<arg::PropMap>;
+    /// The advertised transmission power level, in dBm.
     fn tx_power(&self) -> nonblock::MethodReply<i16>;
+    /// Whether GATT service discovery has completed.
     fn services_resolved(&self) -> nonblock::MethodReply<bool>;
+    /// Whether the device is allowed to wake the host from system suspend.
     fn wake_allowed(&self) -> nonblock::MethodReply<bool>;
     fn set_wake_allowed(&self, value: bool) -> nonblock::MethodReply<()>;
+    /// The flags from the device's advertisement, as the raw AD data.
+    fn advertising_flags(&self) -> nonblock::MethodReply<Vec<u8>>;
+    /// Advertising data which isn't handled by other properties, keyed by AD type.
+    fn advertising_data(
+        &self,
+    ) -> nonblock::MethodReply<
+        ::std::collections::HashMap<u8, arg::Variant<Box<dyn arg::RefArg + 'static>>>,
+    >;
 }
 
 pub const ORG_BLUEZ_DEVICE1_NAME: &str = "org.bluez.Device1";
@@ -54,74 +94,94 @@ impl<'a> OrgBluezDevice1Properties<'a> {
         interfaces.get("org.bluez.Device1").map(Self)
     }
 
+    /// The Bluetooth address of the remote device.
     pub fn address(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Address")
     }
 
+    /// The type of the device's address, either `public` or `random`.
     pub fn address_type(&self) -> Option<&String> {
         arg::prop_cast(self.0, "AddressType")
     }
 
+    /// The name of the remote device, if known. `Alias` should usually be used instead.
     pub fn name(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Name")
     }
 
+    /// The name to show for the remote device. This defaults to the remote name, or the address if
+    /// the name is not known, and setting it to an empty string resets it to the default.
     pub fn alias(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Alias")
     }
 
+    /// The Bluetooth class of device.
     pub fn class(&self) -> Option<u32> {
         arg::prop_cast(self.0, "Class").copied()
     }
 
+    /// The external appearance of the device, as advertised.
     pub fn appearance(&self) -> Option<u16> {
         arg::prop_cast(self.0, "Appearance").copied()
     }
 
+    /// A freedesktop.org icon name for the type of device.
     pub fn icon(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Icon")
     }
 
+    /// Whether the device is paired.
     pub fn paired(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Paired").copied()
     }
 
+    /// Whether the pairing information is stored, so the device is paired after a restart.
     pub fn bonded(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Bonded").copied()
     }
 
+    /// Whether the device is trusted, so that its connections don't need to be authorized by an
+    /// agent.
     pub fn trusted(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Trusted").copied()
     }
 
+    /// Whether connections from the device are rejected.
     pub fn blocked(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Blocked").copied()
     }
 
+    /// Whether the device only supports pre-2.1 legacy pairing.
     pub fn legacy_pairing(&self) -> Option<bool> {
         arg::prop_cast(self.0, "LegacyPairing").copied()
     }
 
+    /// The received signal strength of the device's last inquiry result or advertisement, in dBm.
     pub fn rssi(&self) -> Option<i16> {
         arg::prop_cast(self.0, "RSSI").copied()
     }
 
+    /// Whether the device is connected.
     pub fn connected(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Connected").copied()
     }
 
+    /// The UUIDs of the services which the device advertises or provides.
     pub fn uuids(&self) -> Option<&Vec<String>> {
         arg::prop_cast(self.0, "UUIDs")
     }
 
+    /// Identifies the remote device, in the format used by the Device ID profile.
     pub fn modalias(&self) -> Option<&String> {
         arg::prop_cast(self.0, "Modalias")
     }
 
+    /// The object path of the adapter which the device belongs to.
     pub fn adapter(&self) -> Option<&dbus::Path<'static>> {
         arg::prop_cast(self.0, "Adapter")
     }
 
+    /// Manufacturer-specific advertisement data, keyed by company ID.
     pub fn manufacturer_data(
         &self,
     ) -> Option<&::std::collections::HashMap<u16, arg::Variant<Box<dyn arg::RefArg + 'static>>>>
@@ -129,21 +189,38 @@ impl<'a> OrgBluezDevice1Properties<'a> {
         arg::prop_cast(self.0, "ManufacturerData")
     }
 
+    /// Service advertisement data, keyed by UUID.
     pub fn service_data(&self) -> Option<&arg::PropMap> {
         arg::prop_cast(self.0, "ServiceData")
     }
 
+    /// The advertised transmission power level, in dBm.
     pub fn tx_power(&self) -> Option<i16> {
         arg::prop_cast(self.0, "TxPower").copied()
     }
 
+    /// Whether GATT service discovery has completed.
     pub fn services_resolved(&self) -> Option<bool> {
         arg::prop_cast(self.0, "ServicesResolved").copied()
     }
 
+    /// Whether the device is allowed to wake the host from system suspend.
     pub fn wake_allowed(&self) -> Option<bool> {
         arg::prop_cast(self.0, "WakeAllowed").copied()
     }
+
+    /// The flags from the device's advertisement, as the raw AD data.
+    pub fn advertising_flags(&self) -> Option<&Vec<u8>> {
+        arg::prop_cast(self.0, "AdvertisingFlags")
+    }
+
+    /// Advertising data which isn't handled by other properties, keyed by AD type.
+    pub fn advertising_data(
+        &self,
+    ) -> Option<&::std::collections::HashMap<u8, arg::Variant<Box<dyn arg::RefArg + 'static>>>>
+    {
+        arg::prop_cast(self.0, "AdvertisingData")
+    }
 }
 
 impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezDevice1
@@ -353,6 +430,26 @@ impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezD
         )
     }
 
+    fn advertising_flags(&self) -> nonblock::MethodReply<Vec<u8>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.Device1",
+            "AdvertisingFlags",
+        )
+    }
+
+    fn advertising_data(
+        &self,
+    ) -> nonblock::MethodReply<
+        ::std::collections::HashMap<u8, arg::Variant<Box<dyn arg::RefArg + 'static>>>,
+    > {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.Device1",
+            "AdvertisingData",
+        )
+    }
+
     fn set_alias(&self, value: String) -> nonblock::MethodReply<()> {
         <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::set(
             &self,
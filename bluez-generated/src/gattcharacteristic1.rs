@@ -3,20 +3,40 @@
 use dbus::arg;
 use dbus::nonblock;
 
+/// A GATT characteristic of a remote device, at an object path like
+/// `/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034`.
 pub trait OrgBluezGattCharacteristic1 {
+    /// Reads the value of the characteristic from the device. Supported options include `offset`
+    /// and `mtu`.
     fn read_value(&self, options: arg::PropMap) -> nonblock::MethodReply<Vec<u8>>;
+    /// Writes the value of the characteristic to the device. Supported options include `offset`,
+    /// `type` (`command`, `request` or `reliable`) and `prepare-authorize`.
     fn write_value(&self, value: Vec<u8>, options: arg::PropMap) -> nonblock::MethodReply<()>;
+    /// Acquires a file descriptor to write values to the characteristic with, along with the MTU.
     fn acquire_write(&self, options: arg::PropMap) -> nonblock::MethodReply<(arg::OwnedFd, u16)>;
+    /// Acquires a file descriptor to read notifications of the characteristic's value from, along
+    /// with the MTU.
     fn acquire_notify(&self, options: arg::PropMap) -> nonblock::MethodReply<(arg::OwnedFd, u16)>;
+    /// Starts notifications or indications of the characteristic's value, which are reported as
+    /// changes to the `Value` property.
     fn start_notify(&self) -> nonblock::MethodReply<()>;
+    /// Stops notifications or indications started by this client.
     fn stop_notify(&self) -> nonblock::MethodReply<()>;
+    /// The 128-bit UUID of the characteristic.
     fn uuid(&self) -> nonblock::MethodReply<String>;
+    /// The object path of the service which the characteristic belongs to.
     fn service(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    /// The cached value of the characteristic, updated when it is read or notified.
     fn value(&self) -> nonblock::MethodReply<Vec<u8>>;
+    /// Whether notifications or indications of the characteristic are enabled.
     fn notifying(&self) -> nonblock::MethodReply<bool>;
+    /// The properties and permissions of the characteristic, such as `read`, `write` and `notify`.
     fn flags(&self) -> nonblock::MethodReply<Vec<String>>;
+    /// Whether a file descriptor for writing has been acquired.
     fn write_acquired(&self) -> nonblock::MethodReply<bool>;
+    /// Whether a file descriptor for notifications has been acquired.
     fn notify_acquired(&self) -> nonblock::MethodReply<bool>;
+    /// The ATT MTU of the connection which the characteristic is accessed over.
     fn mtu(&self) -> nonblock::MethodReply<u16>;
 }
 
@@ -32,34 +52,42 @@ impl<'a> OrgBluezGattCharacteristic1Properties<'a> {
         interfaces.get("org.bluez.GattCharacteristic1").map(Self)
     }
 
+    /// The 128-bit UUID of the characteristic.
     pub fn uuid(&self) -> Option<&String> {
         arg::prop_cast(self.0, "UUID")
     }
 
+    /// The object path of the service which the characteristic belongs to.
     pub fn service(&self) -> Option<&dbus::Path<'static>> {
         arg::prop_cast(self.0, "Service")
     }
 
+    /// The cached value of the characteristic, updated when it is read or notified.
     pub fn value(&self) -> Option<&Vec<u8>> {
         arg::prop_cast(self.0, "Value")
     }
 
+    /// Whether notifications or indications of the characteristic are enabled.
     pub fn notifying(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Notifying").copied()
     }
 
+    /// The properties and permissions of the characteristic, such as `read`, `write` and `notify`.
     pub fn flags(&self) -> Option<&Vec<String>> {
         arg::prop_cast(self.0, "Flags")
     }
 
+    /// Whether a file descriptor for writing has been acquired.
     pub fn write_acquired(&self) -> Option<bool> {
         arg::prop_cast(self.0, "WriteAcquired").copied()
     }
 
+    /// Whether a file descriptor for notifications has been acquired.
     pub fn notify_acquired(&self) -> Option<bool> {
         arg::prop_cast(self.0, "NotifyAcquired").copied()
     }
 
+    /// The ATT MTU of the connection which the characteristic is accessed over.
     pub fn mtu(&self) -> Option<u16> {
         arg::prop_cast(self.0, "MTU").copied()
     }
@@ -3,11 +3,19 @@
 use dbus::arg;
 use dbus::nonblock;
 
+/// A GATT descriptor of a remote device, at an object path like
+/// `/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034/desc0056`.
 pub trait OrgBluezGattDescriptor1 {
+    /// Reads the value of the descriptor from the device. Supported options include `offset`.
     fn read_value(&self, options: arg::PropMap) -> nonblock::MethodReply<Vec<u8>>;
+    /// Writes the value of the descriptor to the device. Supported options include `offset` and
+    /// `prepare-authorize`.
     fn write_value(&self, value: Vec<u8>, options: arg::PropMap) -> nonblock::MethodReply<()>;
+    /// The 128-bit UUID of the descriptor.
     fn uuid(&self) -> nonblock::MethodReply<String>;
+    /// The object path of the characteristic which the descriptor belongs to.
     fn characteristic(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    /// The cached value of the descriptor, updated when it is read.
     fn value(&self) -> nonblock::MethodReply<Vec<u8>>;
 }
 
@@ -23,14 +31,17 @@ impl<'a> OrgBluezGattDescriptor1Properties<'a> {
         interfaces.get("org.bluez.GattDescriptor1").map(Self)
     }
 
+    /// The 128-bit UUID of the descriptor.
     pub fn uuid(&self) -> Option<&String> {
         arg::prop_cast(self.0, "UUID")
     }
 
+    /// The object path of the characteristic which the descriptor belongs to.
     pub fn characteristic(&self) -> Option<&dbus::Path<'static>> {
         arg::prop_cast(self.0, "Characteristic")
     }
 
+    /// The cached value of the descriptor, updated when it is read.
     pub fn value(&self) -> Option<&Vec<u8>> {
         arg::prop_cast(self.0, "Value")
     }
@@ -3,10 +3,16 @@
 use dbus::arg;
 use dbus::nonblock;
 
+/// A GATT service of a remote device, at an object path like
+/// `/org/bluez/hci0/dev_11_22_33_44_55_66/service0012`.
 pub trait OrgBluezGattService1 {
+    /// The 128-bit UUID of the service.
     fn uuid(&self) -> nonblock::MethodReply<String>;
+    /// The object path of the device which the service belongs to.
     fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    /// Whether this is a primary service, rather than a secondary one.
     fn primary(&self) -> nonblock::MethodReply<bool>;
+    /// The object paths of the services which this service includes.
     fn includes(&self) -> nonblock::MethodReply<Vec<dbus::Path<'static>>>;
 }
 
@@ -22,18 +28,22 @@ impl<'a> OrgBluezGattService1Properties<'a> {
         interfaces.get("org.bluez.GattService1").map(Self)
     }
 
+    /// The 128-bit UUID of the service.
     pub fn uuid(&self) -> Option<&String> {
         arg::prop_cast(self.0, "UUID")
     }
 
+    /// The object path of the device which the service belongs to.
     pub fn device(&self) -> Option<&dbus::Path<'static>> {
         arg::prop_cast(self.0, "Device")
     }
 
+    /// Whether this is a primary service, rather than a secondary one.
     pub fn primary(&self) -> Option<bool> {
         arg::prop_cast(self.0, "Primary").copied()
     }
 
+    /// The object paths of the services which this service includes.
     pub fn includes(&self) -> Option<&Vec<dbus::Path<'static>>> {
         arg::prop_cast(self.0, "Includes")
     }
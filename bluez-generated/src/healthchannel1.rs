@@ -0,0 +1,74 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.HealthChannel1.xml --interfaces=org.bluez.HealthChannel1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezHealthChannel1 {
+    fn acquire(&self) -> nonblock::MethodReply<arg::OwnedFd>;
+    fn release(&self) -> nonblock::MethodReply<()>;
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn application(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn type_(&self) -> nonblock::MethodReply<String>;
+}
+
+pub const ORG_BLUEZ_HEALTH_CHANNEL1_NAME: &str = "org.bluez.HealthChannel1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezHealthChannel1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezHealthChannel1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.HealthChannel1").map(Self)
+    }
+
+    pub fn device(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Device")
+    }
+
+    pub fn application(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Application")
+    }
+
+    pub fn type_(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Type")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezHealthChannel1
+    for nonblock::Proxy<'a, C>
+{
+    fn acquire(&self) -> nonblock::MethodReply<arg::OwnedFd> {
+        self.method_call("org.bluez.HealthChannel1", "Acquire", ())
+            .and_then(|r: (arg::OwnedFd,)| Ok(r.0))
+    }
+
+    fn release(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.HealthChannel1", "Release", ())
+    }
+
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.HealthChannel1",
+            "Device",
+        )
+    }
+
+    fn application(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.HealthChannel1",
+            "Application",
+        )
+    }
+
+    fn type_(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.HealthChannel1",
+            "Type",
+        )
+    }
+}
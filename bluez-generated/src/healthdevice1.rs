@@ -0,0 +1,110 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.HealthDevice1.xml --interfaces=org.bluez.HealthDevice1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezHealthDevice1 {
+    fn echo(&self) -> nonblock::MethodReply<bool>;
+    fn create_channel(
+        &self,
+        application: dbus::Path,
+        configuration: &str,
+    ) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn destroy_channel(&self, channel: dbus::Path) -> nonblock::MethodReply<()>;
+    fn main_channel(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+}
+
+pub const ORG_BLUEZ_HEALTH_DEVICE1_NAME: &str = "org.bluez.HealthDevice1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezHealthDevice1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezHealthDevice1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.HealthDevice1").map(Self)
+    }
+
+    pub fn main_channel(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "MainChannel")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezHealthDevice1
+    for nonblock::Proxy<'a, C>
+{
+    fn echo(&self) -> nonblock::MethodReply<bool> {
+        self.method_call("org.bluez.HealthDevice1", "Echo", ())
+            .and_then(|r: (bool,)| Ok(r.0))
+    }
+
+    fn create_channel(
+        &self,
+        application: dbus::Path,
+        configuration: &str,
+    ) -> nonblock::MethodReply<dbus::Path<'static>> {
+        self.method_call(
+            "org.bluez.HealthDevice1",
+            "CreateChannel",
+            (application, configuration),
+        )
+        .and_then(|r: (dbus::Path<'static>,)| Ok(r.0))
+    }
+
+    fn destroy_channel(&self, channel: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.HealthDevice1", "DestroyChannel", (channel,))
+    }
+
+    fn main_channel(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.HealthDevice1",
+            "MainChannel",
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct OrgBluezHealthDevice1ChannelConnected {
+    pub channel: dbus::Path<'static>,
+}
+
+impl arg::AppendAll for OrgBluezHealthDevice1ChannelConnected {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.channel, i);
+    }
+}
+
+impl arg::ReadAll for OrgBluezHealthDevice1ChannelConnected {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgBluezHealthDevice1ChannelConnected { channel: i.read()? })
+    }
+}
+
+impl dbus::message::SignalArgs for OrgBluezHealthDevice1ChannelConnected {
+    const NAME: &'static str = "ChannelConnected";
+    const INTERFACE: &'static str = "org.bluez.HealthDevice1";
+}
+
+#[derive(Debug)]
+pub struct OrgBluezHealthDevice1ChannelDeleted {
+    pub channel: dbus::Path<'static>,
+}
+
+impl arg::AppendAll for OrgBluezHealthDevice1ChannelDeleted {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.channel, i);
+    }
+}
+
+impl arg::ReadAll for OrgBluezHealthDevice1ChannelDeleted {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgBluezHealthDevice1ChannelDeleted { channel: i.read()? })
+    }
+}
+
+impl dbus::message::SignalArgs for OrgBluezHealthDevice1ChannelDeleted {
+    const NAME: &'static str = "ChannelDeleted";
+    const INTERFACE: &'static str = "org.bluez.HealthDevice1";
+}
@@ -0,0 +1,272 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.LEAdvertisement1.xml --interfaces=org.bluez.LEAdvertisement1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezLEAdvertisement1 {
+    fn release(&self) -> nonblock::MethodReply<()>;
+    fn type_(&self) -> nonblock::MethodReply<String>;
+    fn service_uuids(&self) -> nonblock::MethodReply<Vec<String>>;
+    fn manufacturer_data(
+        &self,
+    ) -> nonblock::MethodReply<
+        ::std::collections::HashMap<u16, arg::Variant<Box<dyn arg::RefArg + 'static>>>,
+    >;
+    fn solicit_uuids(&self) -> nonblock::MethodReply<Vec<String>>;
+    fn service_data(&self) -> nonblock::MethodReply<arg::PropMap>;
+    fn data(
+        &self,
+    ) -> nonblock::MethodReply<
+        ::std::collections::HashMap<u8, arg::Variant<Box<dyn arg::RefArg + 'static>>>,
+    >;
+    fn discoverable(&self) -> nonblock::MethodReply<bool>;
+    fn discoverable_timeout(&self) -> nonblock::MethodReply<u16>;
+    fn includes(&self) -> nonblock::MethodReply<Vec<String>>;
+    fn local_name(&self) -> nonblock::MethodReply<String>;
+    fn appearance(&self) -> nonblock::MethodReply<u16>;
+    fn duration(&self) -> nonblock::MethodReply<u16>;
+    fn timeout(&self) -> nonblock::MethodReply<u16>;
+    fn secondary_channel(&self) -> nonblock::MethodReply<String>;
+    fn min_interval(&self) -> nonblock::MethodReply<u32>;
+    fn max_interval(&self) -> nonblock::MethodReply<u32>;
+    fn tx_power(&self) -> nonblock::MethodReply<i16>;
+}
+
+pub const ORG_BLUEZ_LEADVERTISEMENT1_NAME: &str = "org.bluez.LEAdvertisement1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezLEAdvertisement1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezLEAdvertisement1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.LEAdvertisement1").map(Self)
+    }
+
+    pub fn type_(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Type")
+    }
+
+    pub fn service_uuids(&self) -> Option<&Vec<String>> {
+        arg::prop_cast(self.0, "ServiceUUIDs")
+    }
+
+    pub fn manufacturer_data(
+        &self,
+    ) -> Option<&::std::collections::HashMap<u16, arg::Variant<Box<dyn arg::RefArg + 'static>>>>
+    {
+        arg::prop_cast(self.0, "ManufacturerData")
+    }
+
+    pub fn solicit_uuids(&self) -> Option<&Vec<String>> {
+        arg::prop_cast(self.0, "SolicitUUIDs")
+    }
+
+    pub fn service_data(&self) -> Option<&arg::PropMap> {
+        arg::prop_cast(self.0, "ServiceData")
+    }
+
+    pub fn data(
+        &self,
+    ) -> Option<&::std::collections::HashMap<u8, arg::Variant<Box<dyn arg::RefArg + 'static>>>>
+    {
+        arg::prop_cast(self.0, "Data")
+    }
+
+    pub fn discoverable(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "Discoverable").copied()
+    }
+
+    pub fn discoverable_timeout(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "DiscoverableTimeout").copied()
+    }
+
+    pub fn includes(&self) -> Option<&Vec<String>> {
+        arg::prop_cast(self.0, "Includes")
+    }
+
+    pub fn local_name(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "LocalName")
+    }
+
+    pub fn appearance(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "Appearance").copied()
+    }
+
+    pub fn duration(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "Duration").copied()
+    }
+
+    pub fn timeout(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "Timeout").copied()
+    }
+
+    pub fn secondary_channel(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "SecondaryChannel")
+    }
+
+    pub fn min_interval(&self) -> Option<u32> {
+        arg::prop_cast(self.0, "MinInterval").copied()
+    }
+
+    pub fn max_interval(&self) -> Option<u32> {
+        arg::prop_cast(self.0, "MaxInterval").copied()
+    }
+
+    pub fn tx_power(&self) -> Option<i16> {
+        arg::prop_cast(self.0, "TxPower").copied()
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezLEAdvertisement1
+    for nonblock::Proxy<'a, C>
+{
+    fn release(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.LEAdvertisement1", "Release", ())
+    }
+
+    fn type_(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "Type",
+        )
+    }
+
+    fn service_uuids(&self) -> nonblock::MethodReply<Vec<String>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "ServiceUUIDs",
+        )
+    }
+
+    fn manufacturer_data(
+        &self,
+    ) -> nonblock::MethodReply<
+        ::std::collections::HashMap<u16, arg::Variant<Box<dyn arg::RefArg + 'static>>>,
+    > {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "ManufacturerData",
+        )
+    }
+
+    fn solicit_uuids(&self) -> nonblock::MethodReply<Vec<String>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "SolicitUUIDs",
+        )
+    }
+
+    fn service_data(&self) -> nonblock::MethodReply<arg::PropMap> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "ServiceData",
+        )
+    }
+
+    fn data(
+        &self,
+    ) -> nonblock::MethodReply<
+        ::std::collections::HashMap<u8, arg::Variant<Box<dyn arg::RefArg + 'static>>>,
+    > {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "Data",
+        )
+    }
+
+    fn discoverable(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "Discoverable",
+        )
+    }
+
+    fn discoverable_timeout(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "DiscoverableTimeout",
+        )
+    }
+
+    fn includes(&self) -> nonblock::MethodReply<Vec<String>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "Includes",
+        )
+    }
+
+    fn local_name(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "LocalName",
+        )
+    }
+
+    fn appearance(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "Appearance",
+        )
+    }
+
+    fn duration(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "Duration",
+        )
+    }
+
+    fn timeout(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "Timeout",
+        )
+    }
+
+    fn secondary_channel(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "SecondaryChannel",
+        )
+    }
+
+    fn min_interval(&self) -> nonblock::MethodReply<u32> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "MinInterval",
+        )
+    }
+
+    fn max_interval(&self) -> nonblock::MethodReply<u32> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "MaxInterval",
+        )
+    }
+
+    fn tx_power(&self) -> nonblock::MethodReply<i16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.LEAdvertisement1",
+            "TxPower",
+        )
+    }
+}
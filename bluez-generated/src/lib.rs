@@ -1,11 +1,28 @@
 // Generated by introspect.sh
-#![allow(clippy::upper_case_acronyms, clippy::needless_borrow)]
+#![allow(
+    clippy::upper_case_acronyms,
+    clippy::needless_borrow,
+    clippy::too_many_arguments,
+    clippy::type_complexity
+)]
 pub mod adapter1;
 pub use adapter1::*;
+pub mod adminpolicyset1;
+pub use adminpolicyset1::*;
+pub mod adminpolicystatus1;
+pub use adminpolicystatus1::*;
+pub mod advertisementmonitor1;
+pub use advertisementmonitor1::*;
+pub mod advertisementmonitormanager1;
+pub use advertisementmonitormanager1::*;
 pub mod agentmanager1;
 pub use agentmanager1::*;
 pub mod battery1;
 pub use battery1::*;
+pub mod batteryprovider1;
+pub use batteryprovider1::*;
+pub mod batteryprovidermanager1;
+pub use batteryprovidermanager1::*;
 pub mod device1;
 pub use device1::*;
 pub mod gattcharacteristic1;
@@ -16,17 +33,91 @@ pub mod gattmanager1;
 pub use gattmanager1::*;
 pub mod gattservice1;
 pub use gattservice1::*;
+pub mod healthchannel1;
+pub use healthchannel1::*;
+pub mod healthdevice1;
+pub use healthdevice1::*;
 pub mod healthmanager1;
 pub use healthmanager1::*;
+pub mod leadvertisement1;
+pub use leadvertisement1::*;
 pub mod leadvertisingmanager1;
 pub use leadvertisingmanager1::*;
 pub mod media1;
 pub use media1::*;
 pub mod mediacontrol1;
 pub use mediacontrol1::*;
+pub mod mediaendpoint1;
+pub use mediaendpoint1::*;
+pub mod mediafolder1;
+pub use mediafolder1::*;
+pub mod mediaitem1;
+pub use mediaitem1::*;
+pub mod mediaplayer1;
+pub use mediaplayer1::*;
+pub mod mediatransport1;
+pub use mediatransport1::*;
 pub mod network1;
 pub use network1::*;
 pub mod networkserver1;
 pub use networkserver1::*;
 pub mod profilemanager1;
 pub use profilemanager1::*;
+pub mod simaccess1;
+pub use simaccess1::*;
+#[cfg(feature = "mesh")]
+pub mod mesh_application1;
+#[cfg(feature = "mesh")]
+pub use mesh_application1::*;
+#[cfg(feature = "mesh")]
+pub mod mesh_element1;
+#[cfg(feature = "mesh")]
+pub use mesh_element1::*;
+#[cfg(feature = "mesh")]
+pub mod mesh_management1;
+#[cfg(feature = "mesh")]
+pub use mesh_management1::*;
+#[cfg(feature = "mesh")]
+pub mod mesh_network1;
+#[cfg(feature = "mesh")]
+pub use mesh_network1::*;
+#[cfg(feature = "mesh")]
+pub mod mesh_node1;
+#[cfg(feature = "mesh")]
+pub use mesh_node1::*;
+#[cfg(feature = "mesh")]
+pub mod mesh_provisioner1;
+#[cfg(feature = "mesh")]
+pub use mesh_provisioner1::*;
+#[cfg(feature = "obex")]
+pub mod obex_client1;
+#[cfg(feature = "obex")]
+pub use obex_client1::*;
+#[cfg(feature = "obex")]
+pub mod obex_filetransfer1;
+#[cfg(feature = "obex")]
+pub use obex_filetransfer1::*;
+#[cfg(feature = "obex")]
+pub mod obex_messageaccess1;
+#[cfg(feature = "obex")]
+pub use obex_messageaccess1::*;
+#[cfg(feature = "obex")]
+pub mod obex_objectpush1;
+#[cfg(feature = "obex")]
+pub use obex_objectpush1::*;
+#[cfg(feature = "obex")]
+pub mod obex_phonebookaccess1;
+#[cfg(feature = "obex")]
+pub use obex_phonebookaccess1::*;
+#[cfg(feature = "obex")]
+pub mod obex_session1;
+#[cfg(feature = "obex")]
+pub use obex_session1::*;
+#[cfg(feature = "obex")]
+pub mod obex_transfer1;
+#[cfg(feature = "obex")]
+pub use obex_transfer1::*;
+pub mod objects;
+pub use objects::*;
+pub mod advertisement_data;
+pub use advertisement_data::*;
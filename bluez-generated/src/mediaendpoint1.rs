@@ -0,0 +1,130 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.MediaEndpoint1.xml --interfaces=org.bluez.MediaEndpoint1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMediaEndpoint1 {
+    fn set_configuration(
+        &self,
+        transport: dbus::Path,
+        properties: arg::PropMap,
+    ) -> nonblock::MethodReply<()>;
+    fn select_configuration(&self, capabilities: Vec<u8>) -> nonblock::MethodReply<Vec<u8>>;
+    fn clear_configuration(&self, transport: dbus::Path) -> nonblock::MethodReply<()>;
+    fn release(&self) -> nonblock::MethodReply<()>;
+    fn uuid(&self) -> nonblock::MethodReply<String>;
+    fn codec(&self) -> nonblock::MethodReply<u8>;
+    fn capabilities(&self) -> nonblock::MethodReply<Vec<u8>>;
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn delay_reporting(&self) -> nonblock::MethodReply<bool>;
+}
+
+pub const ORG_BLUEZ_MEDIA_ENDPOINT1_NAME: &str = "org.bluez.MediaEndpoint1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezMediaEndpoint1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezMediaEndpoint1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.MediaEndpoint1").map(Self)
+    }
+
+    pub fn uuid(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "UUID")
+    }
+
+    pub fn codec(&self) -> Option<u8> {
+        arg::prop_cast(self.0, "Codec").copied()
+    }
+
+    pub fn capabilities(&self) -> Option<&Vec<u8>> {
+        arg::prop_cast(self.0, "Capabilities")
+    }
+
+    pub fn device(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Device")
+    }
+
+    pub fn delay_reporting(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "DelayReporting").copied()
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMediaEndpoint1
+    for nonblock::Proxy<'a, C>
+{
+    fn set_configuration(
+        &self,
+        transport: dbus::Path,
+        properties: arg::PropMap,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.MediaEndpoint1",
+            "SetConfiguration",
+            (transport, properties),
+        )
+    }
+
+    fn select_configuration(&self, capabilities: Vec<u8>) -> nonblock::MethodReply<Vec<u8>> {
+        self.method_call(
+            "org.bluez.MediaEndpoint1",
+            "SelectConfiguration",
+            (capabilities,),
+        )
+        .and_then(|r: (Vec<u8>,)| Ok(r.0))
+    }
+
+    fn clear_configuration(&self, transport: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.MediaEndpoint1",
+            "ClearConfiguration",
+            (transport,),
+        )
+    }
+
+    fn release(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaEndpoint1", "Release", ())
+    }
+
+    fn uuid(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaEndpoint1",
+            "UUID",
+        )
+    }
+
+    fn codec(&self) -> nonblock::MethodReply<u8> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaEndpoint1",
+            "Codec",
+        )
+    }
+
+    fn capabilities(&self) -> nonblock::MethodReply<Vec<u8>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaEndpoint1",
+            "Capabilities",
+        )
+    }
+
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaEndpoint1",
+            "Device",
+        )
+    }
+
+    fn delay_reporting(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaEndpoint1",
+            "DelayReporting",
+        )
+    }
+}
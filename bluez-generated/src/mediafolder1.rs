@@ -0,0 +1,83 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.MediaFolder1.xml --interfaces=org.bluez.MediaFolder1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMediaFolder1 {
+    fn search(
+        &self,
+        value: &str,
+        filter: arg::PropMap,
+    ) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn list_items(
+        &self,
+        filter: arg::PropMap,
+    ) -> nonblock::MethodReply<::std::collections::HashMap<dbus::Path<'static>, arg::PropMap>>;
+    fn change_folder(&self, folder: dbus::Path) -> nonblock::MethodReply<()>;
+    fn number_of_items(&self) -> nonblock::MethodReply<u32>;
+    fn name(&self) -> nonblock::MethodReply<String>;
+}
+
+pub const ORG_BLUEZ_MEDIA_FOLDER1_NAME: &str = "org.bluez.MediaFolder1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezMediaFolder1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezMediaFolder1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.MediaFolder1").map(Self)
+    }
+
+    pub fn number_of_items(&self) -> Option<u32> {
+        arg::prop_cast(self.0, "NumberOfItems").copied()
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Name")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMediaFolder1
+    for nonblock::Proxy<'a, C>
+{
+    fn search(
+        &self,
+        value: &str,
+        filter: arg::PropMap,
+    ) -> nonblock::MethodReply<dbus::Path<'static>> {
+        self.method_call("org.bluez.MediaFolder1", "Search", (value, filter))
+            .and_then(|r: (dbus::Path<'static>,)| Ok(r.0))
+    }
+
+    fn list_items(
+        &self,
+        filter: arg::PropMap,
+    ) -> nonblock::MethodReply<::std::collections::HashMap<dbus::Path<'static>, arg::PropMap>> {
+        self.method_call("org.bluez.MediaFolder1", "ListItems", (filter,))
+            .and_then(
+                |r: (::std::collections::HashMap<dbus::Path<'static>, arg::PropMap>,)| Ok(r.0),
+            )
+    }
+
+    fn change_folder(&self, folder: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaFolder1", "ChangeFolder", (folder,))
+    }
+
+    fn number_of_items(&self) -> nonblock::MethodReply<u32> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaFolder1",
+            "NumberOfItems",
+        )
+    }
+
+    fn name(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaFolder1",
+            "Name",
+        )
+    }
+}
@@ -0,0 +1,112 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.MediaItem1.xml --interfaces=org.bluez.MediaItem1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMediaItem1 {
+    fn play(&self) -> nonblock::MethodReply<()>;
+    fn addto_now_playing(&self) -> nonblock::MethodReply<()>;
+    fn player(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn name(&self) -> nonblock::MethodReply<String>;
+    fn type_(&self) -> nonblock::MethodReply<String>;
+    fn folder_type(&self) -> nonblock::MethodReply<String>;
+    fn playable(&self) -> nonblock::MethodReply<bool>;
+    fn metadata(&self) -> nonblock::MethodReply<arg::PropMap>;
+}
+
+pub const ORG_BLUEZ_MEDIA_ITEM1_NAME: &str = "org.bluez.MediaItem1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezMediaItem1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezMediaItem1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.MediaItem1").map(Self)
+    }
+
+    pub fn player(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Player")
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Name")
+    }
+
+    pub fn type_(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Type")
+    }
+
+    pub fn folder_type(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "FolderType")
+    }
+
+    pub fn playable(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "Playable").copied()
+    }
+
+    pub fn metadata(&self) -> Option<&arg::PropMap> {
+        arg::prop_cast(self.0, "Metadata")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMediaItem1
+    for nonblock::Proxy<'a, C>
+{
+    fn play(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaItem1", "Play", ())
+    }
+
+    fn addto_now_playing(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaItem1", "AddtoNowPlaying", ())
+    }
+
+    fn player(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaItem1",
+            "Player",
+        )
+    }
+
+    fn name(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaItem1",
+            "Name",
+        )
+    }
+
+    fn type_(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaItem1",
+            "Type",
+        )
+    }
+
+    fn folder_type(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaItem1",
+            "FolderType",
+        )
+    }
+
+    fn playable(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaItem1",
+            "Playable",
+        )
+    }
+
+    fn metadata(&self) -> nonblock::MethodReply<arg::PropMap> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaItem1",
+            "Metadata",
+        )
+    }
+}
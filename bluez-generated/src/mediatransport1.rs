@@ -0,0 +1,163 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.MediaTransport1.xml --interfaces=org.bluez.MediaTransport1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMediaTransport1 {
+    fn acquire(&self) -> nonblock::MethodReply<(arg::OwnedFd, u16, u16)>;
+    fn try_acquire(&self) -> nonblock::MethodReply<(arg::OwnedFd, u16, u16)>;
+    fn release(&self) -> nonblock::MethodReply<()>;
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn uuid(&self) -> nonblock::MethodReply<String>;
+    fn codec(&self) -> nonblock::MethodReply<u8>;
+    fn configuration(&self) -> nonblock::MethodReply<Vec<u8>>;
+    fn state(&self) -> nonblock::MethodReply<String>;
+    fn delay(&self) -> nonblock::MethodReply<u16>;
+    fn set_delay(&self, value: u16) -> nonblock::MethodReply<()>;
+    fn volume(&self) -> nonblock::MethodReply<u16>;
+    fn set_volume(&self, value: u16) -> nonblock::MethodReply<()>;
+    fn endpoint(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+}
+
+pub const ORG_BLUEZ_MEDIA_TRANSPORT1_NAME: &str = "org.bluez.MediaTransport1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezMediaTransport1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezMediaTransport1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.MediaTransport1").map(Self)
+    }
+
+    pub fn device(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Device")
+    }
+
+    pub fn uuid(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "UUID")
+    }
+
+    pub fn codec(&self) -> Option<u8> {
+        arg::prop_cast(self.0, "Codec").copied()
+    }
+
+    pub fn configuration(&self) -> Option<&Vec<u8>> {
+        arg::prop_cast(self.0, "Configuration")
+    }
+
+    pub fn state(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "State")
+    }
+
+    pub fn delay(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "Delay").copied()
+    }
+
+    pub fn volume(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "Volume").copied()
+    }
+
+    pub fn endpoint(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Endpoint")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMediaTransport1
+    for nonblock::Proxy<'a, C>
+{
+    fn acquire(&self) -> nonblock::MethodReply<(arg::OwnedFd, u16, u16)> {
+        self.method_call("org.bluez.MediaTransport1", "Acquire", ())
+    }
+
+    fn try_acquire(&self) -> nonblock::MethodReply<(arg::OwnedFd, u16, u16)> {
+        self.method_call("org.bluez.MediaTransport1", "TryAcquire", ())
+    }
+
+    fn release(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaTransport1", "Release", ())
+    }
+
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaTransport1",
+            "Device",
+        )
+    }
+
+    fn uuid(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaTransport1",
+            "UUID",
+        )
+    }
+
+    fn codec(&self) -> nonblock::MethodReply<u8> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaTransport1",
+            "Codec",
+        )
+    }
+
+    fn configuration(&self) -> nonblock::MethodReply<Vec<u8>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaTransport1",
+            "Configuration",
+        )
+    }
+
+    fn state(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaTransport1",
+            "State",
+        )
+    }
+
+    fn delay(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaTransport1",
+            "Delay",
+        )
+    }
+
+    fn volume(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaTransport1",
+            "Volume",
+        )
+    }
+
+    fn endpoint(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaTransport1",
+            "Endpoint",
+        )
+    }
+
+    fn set_delay(&self, value: u16) -> nonblock::MethodReply<()> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::set(
+            &self,
+            "org.bluez.MediaTransport1",
+            "Delay",
+            value,
+        )
+    }
+
+    fn set_volume(&self, value: u16) -> nonblock::MethodReply<()> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::set(
+            &self,
+            "org.bluez.MediaTransport1",
+            "Volume",
+            value,
+        )
+    }
+}
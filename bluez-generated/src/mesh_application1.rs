@@ -0,0 +1,86 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.mesh.Application1.xml --interfaces=org.bluez.mesh.Application1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMeshApplication1 {
+    fn join_complete(&self, token: u64) -> nonblock::MethodReply<()>;
+    fn join_failed(&self, reason: &str) -> nonblock::MethodReply<()>;
+    fn company_id(&self) -> nonblock::MethodReply<u16>;
+    fn product_id(&self) -> nonblock::MethodReply<u16>;
+    fn version_id(&self) -> nonblock::MethodReply<u16>;
+    fn crpl(&self) -> nonblock::MethodReply<u16>;
+}
+
+pub const ORG_BLUEZ_MESH_APPLICATION1_NAME: &str = "org.bluez.mesh.Application1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezMeshApplication1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezMeshApplication1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.mesh.Application1").map(Self)
+    }
+
+    pub fn company_id(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "CompanyID").copied()
+    }
+
+    pub fn product_id(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "ProductID").copied()
+    }
+
+    pub fn version_id(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "VersionID").copied()
+    }
+
+    pub fn crpl(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "CRPL").copied()
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMeshApplication1
+    for nonblock::Proxy<'a, C>
+{
+    fn join_complete(&self, token: u64) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Application1", "JoinComplete", (token,))
+    }
+
+    fn join_failed(&self, reason: &str) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Application1", "JoinFailed", (reason,))
+    }
+
+    fn company_id(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Application1",
+            "CompanyID",
+        )
+    }
+
+    fn product_id(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Application1",
+            "ProductID",
+        )
+    }
+
+    fn version_id(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Application1",
+            "VersionID",
+        )
+    }
+
+    fn crpl(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Application1",
+            "CRPL",
+        )
+    }
+}
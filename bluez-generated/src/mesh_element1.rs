@@ -0,0 +1,122 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.mesh.Element1.xml --interfaces=org.bluez.mesh.Element1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMeshElement1 {
+    fn message_received(
+        &self,
+        source: u16,
+        key_index: u16,
+        destination: arg::Variant<Box<dyn arg::RefArg>>,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()>;
+    fn dev_key_message_received(
+        &self,
+        source: u16,
+        remote: bool,
+        net_index: u16,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()>;
+    fn update_model_configuration(
+        &self,
+        model_id: u16,
+        config: arg::PropMap,
+    ) -> nonblock::MethodReply<()>;
+    fn models(&self) -> nonblock::MethodReply<Vec<(u16, arg::PropMap)>>;
+    fn vendor_models(&self) -> nonblock::MethodReply<Vec<(u16, u16, arg::PropMap)>>;
+    fn location(&self) -> nonblock::MethodReply<u16>;
+}
+
+pub const ORG_BLUEZ_MESH_ELEMENT1_NAME: &str = "org.bluez.mesh.Element1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezMeshElement1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezMeshElement1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.mesh.Element1").map(Self)
+    }
+
+    pub fn models(&self) -> Option<&Vec<(u16, arg::PropMap)>> {
+        arg::prop_cast(self.0, "Models")
+    }
+
+    pub fn vendor_models(&self) -> Option<&Vec<(u16, u16, arg::PropMap)>> {
+        arg::prop_cast(self.0, "VendorModels")
+    }
+
+    pub fn location(&self) -> Option<u16> {
+        arg::prop_cast(self.0, "Location").copied()
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMeshElement1
+    for nonblock::Proxy<'a, C>
+{
+    fn message_received(
+        &self,
+        source: u16,
+        key_index: u16,
+        destination: arg::Variant<Box<dyn arg::RefArg>>,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Element1",
+            "MessageReceived",
+            (source, key_index, destination, data),
+        )
+    }
+
+    fn dev_key_message_received(
+        &self,
+        source: u16,
+        remote: bool,
+        net_index: u16,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Element1",
+            "DevKeyMessageReceived",
+            (source, remote, net_index, data),
+        )
+    }
+
+    fn update_model_configuration(
+        &self,
+        model_id: u16,
+        config: arg::PropMap,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Element1",
+            "UpdateModelConfiguration",
+            (model_id, config),
+        )
+    }
+
+    fn models(&self) -> nonblock::MethodReply<Vec<(u16, arg::PropMap)>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Element1",
+            "Models",
+        )
+    }
+
+    fn vendor_models(&self) -> nonblock::MethodReply<Vec<(u16, u16, arg::PropMap)>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Element1",
+            "VendorModels",
+        )
+    }
+
+    fn location(&self) -> nonblock::MethodReply<u16> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Element1",
+            "Location",
+        )
+    }
+}
@@ -0,0 +1,137 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.mesh.Management1.xml --interfaces=org.bluez.mesh.Management1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMeshManagement1 {
+    fn add_node(&self, uuid: Vec<u8>, options: arg::PropMap) -> nonblock::MethodReply<()>;
+    fn import_remote_node(
+        &self,
+        primary: u16,
+        count: u8,
+        device_key: Vec<u8>,
+    ) -> nonblock::MethodReply<()>;
+    fn delete_remote_node(&self, primary: u16, count: u8) -> nonblock::MethodReply<()>;
+    fn unprovisioned_scan(&self, options: arg::PropMap) -> nonblock::MethodReply<()>;
+    fn unprovisioned_scan_cancel(&self) -> nonblock::MethodReply<()>;
+    fn create_subnet(&self, net_index: u16) -> nonblock::MethodReply<()>;
+    fn import_subnet(&self, net_index: u16, net_key: Vec<u8>) -> nonblock::MethodReply<()>;
+    fn update_subnet(&self, net_index: u16) -> nonblock::MethodReply<()>;
+    fn delete_subnet(&self, net_index: u16) -> nonblock::MethodReply<()>;
+    fn set_key_phase(&self, net_index: u16, phase: u8) -> nonblock::MethodReply<()>;
+    fn create_app_key(&self, net_index: u16, app_index: u16) -> nonblock::MethodReply<()>;
+    fn import_app_key(
+        &self,
+        net_index: u16,
+        app_index: u16,
+        app_key: Vec<u8>,
+    ) -> nonblock::MethodReply<()>;
+    fn update_app_key(&self, app_index: u16) -> nonblock::MethodReply<()>;
+    fn delete_app_key(&self, app_index: u16) -> nonblock::MethodReply<()>;
+    fn export_keys(&self) -> nonblock::MethodReply<arg::PropMap>;
+}
+
+pub const ORG_BLUEZ_MESH_MANAGEMENT1_NAME: &str = "org.bluez.mesh.Management1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMeshManagement1
+    for nonblock::Proxy<'a, C>
+{
+    fn add_node(&self, uuid: Vec<u8>, options: arg::PropMap) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Management1", "AddNode", (uuid, options))
+    }
+
+    fn import_remote_node(
+        &self,
+        primary: u16,
+        count: u8,
+        device_key: Vec<u8>,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Management1",
+            "ImportRemoteNode",
+            (primary, count, device_key),
+        )
+    }
+
+    fn delete_remote_node(&self, primary: u16, count: u8) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Management1",
+            "DeleteRemoteNode",
+            (primary, count),
+        )
+    }
+
+    fn unprovisioned_scan(&self, options: arg::PropMap) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Management1",
+            "UnprovisionedScan",
+            (options,),
+        )
+    }
+
+    fn unprovisioned_scan_cancel(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Management1", "UnprovisionedScanCancel", ())
+    }
+
+    fn create_subnet(&self, net_index: u16) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Management1", "CreateSubnet", (net_index,))
+    }
+
+    fn import_subnet(&self, net_index: u16, net_key: Vec<u8>) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Management1",
+            "ImportSubnet",
+            (net_index, net_key),
+        )
+    }
+
+    fn update_subnet(&self, net_index: u16) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Management1", "UpdateSubnet", (net_index,))
+    }
+
+    fn delete_subnet(&self, net_index: u16) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Management1", "DeleteSubnet", (net_index,))
+    }
+
+    fn set_key_phase(&self, net_index: u16, phase: u8) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Management1",
+            "SetKeyPhase",
+            (net_index, phase),
+        )
+    }
+
+    fn create_app_key(&self, net_index: u16, app_index: u16) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Management1",
+            "CreateAppKey",
+            (net_index, app_index),
+        )
+    }
+
+    fn import_app_key(
+        &self,
+        net_index: u16,
+        app_index: u16,
+        app_key: Vec<u8>,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Management1",
+            "ImportAppKey",
+            (net_index, app_index, app_key),
+        )
+    }
+
+    fn update_app_key(&self, app_index: u16) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Management1", "UpdateAppKey", (app_index,))
+    }
+
+    fn delete_app_key(&self, app_index: u16) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Management1", "DeleteAppKey", (app_index,))
+    }
+
+    fn export_keys(&self) -> nonblock::MethodReply<arg::PropMap> {
+        self.method_call("org.bluez.mesh.Management1", "ExportKeys", ())
+            .and_then(|r: (arg::PropMap,)| Ok(r.0))
+    }
+}
@@ -0,0 +1,77 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.mesh.Network1.xml --interfaces=org.bluez.mesh.Network1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMeshNetwork1 {
+    fn join(&self, app_root: dbus::Path, uuid: Vec<u8>) -> nonblock::MethodReply<()>;
+    fn cancel(&self) -> nonblock::MethodReply<()>;
+    fn attach(
+        &self,
+        app_root: dbus::Path,
+        token: u64,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, Vec<(u8, Vec<(u16, arg::PropMap)>)>)>;
+    fn leave(&self, token: u64) -> nonblock::MethodReply<()>;
+    fn create_network(&self, app_root: dbus::Path, uuid: Vec<u8>) -> nonblock::MethodReply<()>;
+    fn import(
+        &self,
+        app_root: dbus::Path,
+        uuid: Vec<u8>,
+        dev_key: Vec<u8>,
+        net_key: Vec<u8>,
+        net_index: u16,
+        flags: arg::PropMap,
+        iv_index: u32,
+        unicast: u16,
+    ) -> nonblock::MethodReply<()>;
+}
+
+pub const ORG_BLUEZ_MESH_NETWORK1_NAME: &str = "org.bluez.mesh.Network1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMeshNetwork1
+    for nonblock::Proxy<'a, C>
+{
+    fn join(&self, app_root: dbus::Path, uuid: Vec<u8>) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Network1", "Join", (app_root, uuid))
+    }
+
+    fn cancel(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Network1", "Cancel", ())
+    }
+
+    fn attach(
+        &self,
+        app_root: dbus::Path,
+        token: u64,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, Vec<(u8, Vec<(u16, arg::PropMap)>)>)> {
+        self.method_call("org.bluez.mesh.Network1", "Attach", (app_root, token))
+    }
+
+    fn leave(&self, token: u64) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Network1", "Leave", (token,))
+    }
+
+    fn create_network(&self, app_root: dbus::Path, uuid: Vec<u8>) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.mesh.Network1", "CreateNetwork", (app_root, uuid))
+    }
+
+    fn import(
+        &self,
+        app_root: dbus::Path,
+        uuid: Vec<u8>,
+        dev_key: Vec<u8>,
+        net_key: Vec<u8>,
+        net_index: u16,
+        flags: arg::PropMap,
+        iv_index: u32,
+        unicast: u16,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Network1",
+            "Import",
+            (
+                app_root, uuid, dev_key, net_key, net_index, flags, iv_index, unicast,
+            ),
+        )
+    }
+}
@@ -0,0 +1,230 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.mesh.Node1.xml --interfaces=org.bluez.mesh.Node1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMeshNode1 {
+    fn send(
+        &self,
+        element_path: dbus::Path,
+        destination: u16,
+        key_index: u16,
+        options: arg::PropMap,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()>;
+    fn dev_key_send(
+        &self,
+        element_path: dbus::Path,
+        destination: u16,
+        remote: bool,
+        net_index: u16,
+        options: arg::PropMap,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()>;
+    fn add_net_key(
+        &self,
+        element_path: dbus::Path,
+        destination: u16,
+        subnet_index: u16,
+        net_index: u16,
+        update: bool,
+    ) -> nonblock::MethodReply<()>;
+    fn add_app_key(
+        &self,
+        element_path: dbus::Path,
+        destination: u16,
+        app_index: u16,
+        net_index: u16,
+        update: bool,
+    ) -> nonblock::MethodReply<()>;
+    fn publish(
+        &self,
+        element_path: dbus::Path,
+        model: u16,
+        options: arg::PropMap,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()>;
+    fn features(&self) -> nonblock::MethodReply<arg::PropMap>;
+    fn beacon(&self) -> nonblock::MethodReply<bool>;
+    fn iv_update(&self) -> nonblock::MethodReply<bool>;
+    fn iv_index(&self) -> nonblock::MethodReply<u32>;
+    fn seconds_since_last_heard(&self) -> nonblock::MethodReply<u32>;
+    fn addresses(&self) -> nonblock::MethodReply<Vec<u16>>;
+    fn sequence_number(&self) -> nonblock::MethodReply<u32>;
+}
+
+pub const ORG_BLUEZ_MESH_NODE1_NAME: &str = "org.bluez.mesh.Node1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezMeshNode1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezMeshNode1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.mesh.Node1").map(Self)
+    }
+
+    pub fn features(&self) -> Option<&arg::PropMap> {
+        arg::prop_cast(self.0, "Features")
+    }
+
+    pub fn beacon(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "Beacon").copied()
+    }
+
+    pub fn iv_update(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "IvUpdate").copied()
+    }
+
+    pub fn iv_index(&self) -> Option<u32> {
+        arg::prop_cast(self.0, "IvIndex").copied()
+    }
+
+    pub fn seconds_since_last_heard(&self) -> Option<u32> {
+        arg::prop_cast(self.0, "SecondsSinceLastHeard").copied()
+    }
+
+    pub fn addresses(&self) -> Option<&Vec<u16>> {
+        arg::prop_cast(self.0, "Addresses")
+    }
+
+    pub fn sequence_number(&self) -> Option<u32> {
+        arg::prop_cast(self.0, "SequenceNumber").copied()
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMeshNode1
+    for nonblock::Proxy<'a, C>
+{
+    fn send(
+        &self,
+        element_path: dbus::Path,
+        destination: u16,
+        key_index: u16,
+        options: arg::PropMap,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Node1",
+            "Send",
+            (element_path, destination, key_index, options, data),
+        )
+    }
+
+    fn dev_key_send(
+        &self,
+        element_path: dbus::Path,
+        destination: u16,
+        remote: bool,
+        net_index: u16,
+        options: arg::PropMap,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Node1",
+            "DevKeySend",
+            (element_path, destination, remote, net_index, options, data),
+        )
+    }
+
+    fn add_net_key(
+        &self,
+        element_path: dbus::Path,
+        destination: u16,
+        subnet_index: u16,
+        net_index: u16,
+        update: bool,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Node1",
+            "AddNetKey",
+            (element_path, destination, subnet_index, net_index, update),
+        )
+    }
+
+    fn add_app_key(
+        &self,
+        element_path: dbus::Path,
+        destination: u16,
+        app_index: u16,
+        net_index: u16,
+        update: bool,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Node1",
+            "AddAppKey",
+            (element_path, destination, app_index, net_index, update),
+        )
+    }
+
+    fn publish(
+        &self,
+        element_path: dbus::Path,
+        model: u16,
+        options: arg::PropMap,
+        data: Vec<u8>,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Node1",
+            "Publish",
+            (element_path, model, options, data),
+        )
+    }
+
+    fn features(&self) -> nonblock::MethodReply<arg::PropMap> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Node1",
+            "Features",
+        )
+    }
+
+    fn beacon(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Node1",
+            "Beacon",
+        )
+    }
+
+    fn iv_update(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Node1",
+            "IvUpdate",
+        )
+    }
+
+    fn iv_index(&self) -> nonblock::MethodReply<u32> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Node1",
+            "IvIndex",
+        )
+    }
+
+    fn seconds_since_last_heard(&self) -> nonblock::MethodReply<u32> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Node1",
+            "SecondsSinceLastHeard",
+        )
+    }
+
+    fn addresses(&self) -> nonblock::MethodReply<Vec<u16>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Node1",
+            "Addresses",
+        )
+    }
+
+    fn sequence_number(&self) -> nonblock::MethodReply<u32> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.mesh.Node1",
+            "SequenceNumber",
+        )
+    }
+}
@@ -0,0 +1,65 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.mesh.Provisioner1.xml --interfaces=org.bluez.mesh.Provisioner1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMeshProvisioner1 {
+    fn scan_result(
+        &self,
+        rssi: i16,
+        data: Vec<u8>,
+        options: arg::PropMap,
+    ) -> nonblock::MethodReply<()>;
+    fn request_prov_data(&self, count: u8) -> nonblock::MethodReply<(u16, u16)>;
+    fn add_node_complete(
+        &self,
+        uuid: Vec<u8>,
+        unicast: u16,
+        count: u8,
+    ) -> nonblock::MethodReply<()>;
+    fn add_node_failed(&self, uuid: Vec<u8>, reason: &str) -> nonblock::MethodReply<()>;
+}
+
+pub const ORG_BLUEZ_MESH_PROVISIONER1_NAME: &str = "org.bluez.mesh.Provisioner1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMeshProvisioner1
+    for nonblock::Proxy<'a, C>
+{
+    fn scan_result(
+        &self,
+        rssi: i16,
+        data: Vec<u8>,
+        options: arg::PropMap,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Provisioner1",
+            "ScanResult",
+            (rssi, data, options),
+        )
+    }
+
+    fn request_prov_data(&self, count: u8) -> nonblock::MethodReply<(u16, u16)> {
+        self.method_call("org.bluez.mesh.Provisioner1", "RequestProvData", (count,))
+    }
+
+    fn add_node_complete(
+        &self,
+        uuid: Vec<u8>,
+        unicast: u16,
+        count: u8,
+    ) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Provisioner1",
+            "AddNodeComplete",
+            (uuid, unicast, count),
+        )
+    }
+
+    fn add_node_failed(&self, uuid: Vec<u8>, reason: &str) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.mesh.Provisioner1",
+            "AddNodeFailed",
+            (uuid, reason),
+        )
+    }
+}
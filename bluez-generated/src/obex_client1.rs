@@ -0,0 +1,36 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.obex.Client1.xml --interfaces=org.bluez.obex.Client1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezObexClient1 {
+    fn create_session(
+        &self,
+        destination: &str,
+        args: arg::PropMap,
+    ) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn remove_session(&self, session: dbus::Path) -> nonblock::MethodReply<()>;
+}
+
+pub const ORG_BLUEZ_OBEX_CLIENT1_NAME: &str = "org.bluez.obex.Client1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezObexClient1
+    for nonblock::Proxy<'a, C>
+{
+    fn create_session(
+        &self,
+        destination: &str,
+        args: arg::PropMap,
+    ) -> nonblock::MethodReply<dbus::Path<'static>> {
+        self.method_call(
+            "org.bluez.obex.Client1",
+            "CreateSession",
+            (destination, args),
+        )
+        .and_then(|r: (dbus::Path<'static>,)| Ok(r.0))
+    }
+
+    fn remove_session(&self, session: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.Client1", "RemoveSession", (session,))
+    }
+}
@@ -0,0 +1,86 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.obex.FileTransfer1.xml --interfaces=org.bluez.obex.FileTransfer1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezObexFileTransfer1 {
+    fn change_folder(&self, folder: &str) -> nonblock::MethodReply<()>;
+    fn create_folder(&self, folder: &str) -> nonblock::MethodReply<()>;
+    fn list_folder(&self) -> nonblock::MethodReply<Vec<arg::PropMap>>;
+    fn get_file(
+        &self,
+        targetfile: &str,
+        sourcefile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)>;
+    fn put_file(
+        &self,
+        sourcefile: &str,
+        targetfile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)>;
+    fn copy_file(&self, sourcefile: &str, targetfile: &str) -> nonblock::MethodReply<()>;
+    fn move_file(&self, sourcefile: &str, targetfile: &str) -> nonblock::MethodReply<()>;
+    fn delete(&self, file: &str) -> nonblock::MethodReply<()>;
+}
+
+pub const ORG_BLUEZ_OBEX_FILE_TRANSFER1_NAME: &str = "org.bluez.obex.FileTransfer1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezObexFileTransfer1
+    for nonblock::Proxy<'a, C>
+{
+    fn change_folder(&self, folder: &str) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.FileTransfer1", "ChangeFolder", (folder,))
+    }
+
+    fn create_folder(&self, folder: &str) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.FileTransfer1", "CreateFolder", (folder,))
+    }
+
+    fn list_folder(&self) -> nonblock::MethodReply<Vec<arg::PropMap>> {
+        self.method_call("org.bluez.obex.FileTransfer1", "ListFolder", ())
+            .and_then(|r: (Vec<arg::PropMap>,)| Ok(r.0))
+    }
+
+    fn get_file(
+        &self,
+        targetfile: &str,
+        sourcefile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)> {
+        self.method_call(
+            "org.bluez.obex.FileTransfer1",
+            "GetFile",
+            (targetfile, sourcefile),
+        )
+    }
+
+    fn put_file(
+        &self,
+        sourcefile: &str,
+        targetfile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)> {
+        self.method_call(
+            "org.bluez.obex.FileTransfer1",
+            "PutFile",
+            (sourcefile, targetfile),
+        )
+    }
+
+    fn copy_file(&self, sourcefile: &str, targetfile: &str) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.obex.FileTransfer1",
+            "CopyFile",
+            (sourcefile, targetfile),
+        )
+    }
+
+    fn move_file(&self, sourcefile: &str, targetfile: &str) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.obex.FileTransfer1",
+            "MoveFile",
+            (sourcefile, targetfile),
+        )
+    }
+
+    fn delete(&self, file: &str) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.FileTransfer1", "Delete", (file,))
+    }
+}
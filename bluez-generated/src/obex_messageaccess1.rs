@@ -0,0 +1,72 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.obex.MessageAccess1.xml --interfaces=org.bluez.obex.MessageAccess1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezObexMessageAccess1 {
+    fn set_folder(&self, name: &str) -> nonblock::MethodReply<()>;
+    fn list_folders(&self, filter: arg::PropMap) -> nonblock::MethodReply<Vec<arg::PropMap>>;
+    fn list_filter_fields(&self) -> nonblock::MethodReply<Vec<String>>;
+    fn list_messages(
+        &self,
+        folder: &str,
+        filter: arg::PropMap,
+    ) -> nonblock::MethodReply<::std::collections::HashMap<dbus::Path<'static>, arg::PropMap>>;
+    fn update_inbox(&self) -> nonblock::MethodReply<()>;
+    fn push_message(
+        &self,
+        sourcefile: &str,
+        folder: &str,
+        args: arg::PropMap,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)>;
+}
+
+pub const ORG_BLUEZ_OBEX_MESSAGE_ACCESS1_NAME: &str = "org.bluez.obex.MessageAccess1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezObexMessageAccess1
+    for nonblock::Proxy<'a, C>
+{
+    fn set_folder(&self, name: &str) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.MessageAccess1", "SetFolder", (name,))
+    }
+
+    fn list_folders(&self, filter: arg::PropMap) -> nonblock::MethodReply<Vec<arg::PropMap>> {
+        self.method_call("org.bluez.obex.MessageAccess1", "ListFolders", (filter,))
+            .and_then(|r: (Vec<arg::PropMap>,)| Ok(r.0))
+    }
+
+    fn list_filter_fields(&self) -> nonblock::MethodReply<Vec<String>> {
+        self.method_call("org.bluez.obex.MessageAccess1", "ListFilterFields", ())
+            .and_then(|r: (Vec<String>,)| Ok(r.0))
+    }
+
+    fn list_messages(
+        &self,
+        folder: &str,
+        filter: arg::PropMap,
+    ) -> nonblock::MethodReply<::std::collections::HashMap<dbus::Path<'static>, arg::PropMap>> {
+        self.method_call(
+            "org.bluez.obex.MessageAccess1",
+            "ListMessages",
+            (folder, filter),
+        )
+        .and_then(|r: (::std::collections::HashMap<dbus::Path<'static>, arg::PropMap>,)| Ok(r.0))
+    }
+
+    fn update_inbox(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.MessageAccess1", "UpdateInbox", ())
+    }
+
+    fn push_message(
+        &self,
+        sourcefile: &str,
+        folder: &str,
+        args: arg::PropMap,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)> {
+        self.method_call(
+            "org.bluez.obex.MessageAccess1",
+            "PushMessage",
+            (sourcefile, folder, args),
+        )
+    }
+}
@@ -0,0 +1,56 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.obex.ObjectPush1.xml --interfaces=org.bluez.obex.ObjectPush1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezObexObjectPush1 {
+    fn send_file(
+        &self,
+        sourcefile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)>;
+    fn pull_business_card(
+        &self,
+        targetfile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)>;
+    fn exchange_business_cards(
+        &self,
+        clientfile: &str,
+        targetfile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)>;
+}
+
+pub const ORG_BLUEZ_OBEX_OBJECT_PUSH1_NAME: &str = "org.bluez.obex.ObjectPush1";
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezObexObjectPush1
+    for nonblock::Proxy<'a, C>
+{
+    fn send_file(
+        &self,
+        sourcefile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)> {
+        self.method_call("org.bluez.obex.ObjectPush1", "SendFile", (sourcefile,))
+    }
+
+    fn pull_business_card(
+        &self,
+        targetfile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)> {
+        self.method_call(
+            "org.bluez.obex.ObjectPush1",
+            "PullBusinessCard",
+            (targetfile,),
+        )
+    }
+
+    fn exchange_business_cards(
+        &self,
+        clientfile: &str,
+        targetfile: &str,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)> {
+        self.method_call(
+            "org.bluez.obex.ObjectPush1",
+            "ExchangeBusinessCards",
+            (clientfile, targetfile),
+        )
+    }
+}
@@ -0,0 +1,177 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.obex.PhonebookAccess1.xml --interfaces=org.bluez.obex.PhonebookAccess1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezObexPhonebookAccess1 {
+    fn select(&self, location: &str, phonebook: &str) -> nonblock::MethodReply<()>;
+    fn pull_all(
+        &self,
+        targetfile: &str,
+        filters: arg::PropMap,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)>;
+    fn list(&self, filters: arg::PropMap) -> nonblock::MethodReply<Vec<(String, String)>>;
+    fn pull(
+        &self,
+        vcard: &str,
+        targetfile: &str,
+        filters: arg::PropMap,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)>;
+    fn search(
+        &self,
+        field: &str,
+        value: &str,
+        filters: arg::PropMap,
+    ) -> nonblock::MethodReply<Vec<(String, String)>>;
+    fn get_size(&self) -> nonblock::MethodReply<u16>;
+    fn update_version(&self) -> nonblock::MethodReply<()>;
+    fn list_filter_fields(&self) -> nonblock::MethodReply<Vec<String>>;
+    fn folder(&self) -> nonblock::MethodReply<String>;
+    fn database_identifier(&self) -> nonblock::MethodReply<String>;
+    fn primary_counter(&self) -> nonblock::MethodReply<String>;
+    fn secondary_counter(&self) -> nonblock::MethodReply<String>;
+    fn fixed_image_size(&self) -> nonblock::MethodReply<bool>;
+}
+
+pub const ORG_BLUEZ_OBEX_PHONEBOOK_ACCESS1_NAME: &str = "org.bluez.obex.PhonebookAccess1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezObexPhonebookAccess1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezObexPhonebookAccess1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.obex.PhonebookAccess1").map(Self)
+    }
+
+    pub fn folder(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Folder")
+    }
+
+    pub fn database_identifier(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "DatabaseIdentifier")
+    }
+
+    pub fn primary_counter(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "PrimaryCounter")
+    }
+
+    pub fn secondary_counter(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "SecondaryCounter")
+    }
+
+    pub fn fixed_image_size(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "FixedImageSize").copied()
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezObexPhonebookAccess1
+    for nonblock::Proxy<'a, C>
+{
+    fn select(&self, location: &str, phonebook: &str) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.obex.PhonebookAccess1",
+            "Select",
+            (location, phonebook),
+        )
+    }
+
+    fn pull_all(
+        &self,
+        targetfile: &str,
+        filters: arg::PropMap,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)> {
+        self.method_call(
+            "org.bluez.obex.PhonebookAccess1",
+            "PullAll",
+            (targetfile, filters),
+        )
+    }
+
+    fn list(&self, filters: arg::PropMap) -> nonblock::MethodReply<Vec<(String, String)>> {
+        self.method_call("org.bluez.obex.PhonebookAccess1", "List", (filters,))
+            .and_then(|r: (Vec<(String, String)>,)| Ok(r.0))
+    }
+
+    fn pull(
+        &self,
+        vcard: &str,
+        targetfile: &str,
+        filters: arg::PropMap,
+    ) -> nonblock::MethodReply<(dbus::Path<'static>, arg::PropMap)> {
+        self.method_call(
+            "org.bluez.obex.PhonebookAccess1",
+            "Pull",
+            (vcard, targetfile, filters),
+        )
+    }
+
+    fn search(
+        &self,
+        field: &str,
+        value: &str,
+        filters: arg::PropMap,
+    ) -> nonblock::MethodReply<Vec<(String, String)>> {
+        self.method_call(
+            "org.bluez.obex.PhonebookAccess1",
+            "Search",
+            (field, value, filters),
+        )
+        .and_then(|r: (Vec<(String, String)>,)| Ok(r.0))
+    }
+
+    fn get_size(&self) -> nonblock::MethodReply<u16> {
+        self.method_call("org.bluez.obex.PhonebookAccess1", "GetSize", ())
+            .and_then(|r: (u16,)| Ok(r.0))
+    }
+
+    fn update_version(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.PhonebookAccess1", "UpdateVersion", ())
+    }
+
+    fn list_filter_fields(&self) -> nonblock::MethodReply<Vec<String>> {
+        self.method_call("org.bluez.obex.PhonebookAccess1", "ListFilterFields", ())
+            .and_then(|r: (Vec<String>,)| Ok(r.0))
+    }
+
+    fn folder(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.PhonebookAccess1",
+            "Folder",
+        )
+    }
+
+    fn database_identifier(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.PhonebookAccess1",
+            "DatabaseIdentifier",
+        )
+    }
+
+    fn primary_counter(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.PhonebookAccess1",
+            "PrimaryCounter",
+        )
+    }
+
+    fn secondary_counter(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.PhonebookAccess1",
+            "SecondaryCounter",
+        )
+    }
+
+    fn fixed_image_size(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.PhonebookAccess1",
+            "FixedImageSize",
+        )
+    }
+}
@@ -0,0 +1,95 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.obex.Session1.xml --interfaces=org.bluez.obex.Session1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezObexSession1 {
+    fn get_capabilities(&self) -> nonblock::MethodReply<String>;
+    fn source(&self) -> nonblock::MethodReply<String>;
+    fn destination(&self) -> nonblock::MethodReply<String>;
+    fn channel(&self) -> nonblock::MethodReply<u8>;
+    fn target(&self) -> nonblock::MethodReply<String>;
+    fn root(&self) -> nonblock::MethodReply<String>;
+}
+
+pub const ORG_BLUEZ_OBEX_SESSION1_NAME: &str = "org.bluez.obex.Session1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezObexSession1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezObexSession1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.obex.Session1").map(Self)
+    }
+
+    pub fn source(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Source")
+    }
+
+    pub fn destination(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Destination")
+    }
+
+    pub fn channel(&self) -> Option<u8> {
+        arg::prop_cast(self.0, "Channel").copied()
+    }
+
+    pub fn target(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Target")
+    }
+
+    pub fn root(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Root")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezObexSession1
+    for nonblock::Proxy<'a, C>
+{
+    fn get_capabilities(&self) -> nonblock::MethodReply<String> {
+        self.method_call("org.bluez.obex.Session1", "GetCapabilities", ())
+            .and_then(|r: (String,)| Ok(r.0))
+    }
+
+    fn source(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Session1",
+            "Source",
+        )
+    }
+
+    fn destination(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Session1",
+            "Destination",
+        )
+    }
+
+    fn channel(&self) -> nonblock::MethodReply<u8> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Session1",
+            "Channel",
+        )
+    }
+
+    fn target(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Session1",
+            "Target",
+        )
+    }
+
+    fn root(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Session1",
+            "Root",
+        )
+    }
+}
@@ -0,0 +1,143 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.obex.Transfer1.xml --interfaces=org.bluez.obex.Transfer1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezObexTransfer1 {
+    fn cancel(&self) -> nonblock::MethodReply<()>;
+    fn suspend(&self) -> nonblock::MethodReply<()>;
+    fn resume(&self) -> nonblock::MethodReply<()>;
+    fn status(&self) -> nonblock::MethodReply<String>;
+    fn session(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn name(&self) -> nonblock::MethodReply<String>;
+    fn type_(&self) -> nonblock::MethodReply<String>;
+    fn time(&self) -> nonblock::MethodReply<u64>;
+    fn size(&self) -> nonblock::MethodReply<u64>;
+    fn transferred(&self) -> nonblock::MethodReply<u64>;
+    fn filename(&self) -> nonblock::MethodReply<String>;
+}
+
+pub const ORG_BLUEZ_OBEX_TRANSFER1_NAME: &str = "org.bluez.obex.Transfer1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezObexTransfer1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezObexTransfer1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.obex.Transfer1").map(Self)
+    }
+
+    pub fn status(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Status")
+    }
+
+    pub fn session(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Session")
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Name")
+    }
+
+    pub fn type_(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Type")
+    }
+
+    pub fn time(&self) -> Option<u64> {
+        arg::prop_cast(self.0, "Time").copied()
+    }
+
+    pub fn size(&self) -> Option<u64> {
+        arg::prop_cast(self.0, "Size").copied()
+    }
+
+    pub fn transferred(&self) -> Option<u64> {
+        arg::prop_cast(self.0, "Transferred").copied()
+    }
+
+    pub fn filename(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Filename")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezObexTransfer1
+    for nonblock::Proxy<'a, C>
+{
+    fn cancel(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.Transfer1", "Cancel", ())
+    }
+
+    fn suspend(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.Transfer1", "Suspend", ())
+    }
+
+    fn resume(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.obex.Transfer1", "Resume", ())
+    }
+
+    fn status(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Transfer1",
+            "Status",
+        )
+    }
+
+    fn session(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Transfer1",
+            "Session",
+        )
+    }
+
+    fn name(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Transfer1",
+            "Name",
+        )
+    }
+
+    fn type_(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Transfer1",
+            "Type",
+        )
+    }
+
+    fn time(&self) -> nonblock::MethodReply<u64> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Transfer1",
+            "Time",
+        )
+    }
+
+    fn size(&self) -> nonblock::MethodReply<u64> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Transfer1",
+            "Size",
+        )
+    }
+
+    fn transferred(&self) -> nonblock::MethodReply<u64> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Transfer1",
+            "Transferred",
+        )
+    }
+
+    fn filename(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.obex.Transfer1",
+            "Filename",
+        )
+    }
+}
@@ -0,0 +1,181 @@
+// Generated by introspect.sh
+use crate::*;
+use dbus::arg;
+use dbus::nonblock;
+use dbus::nonblock::stdintf::org_freedesktop_dbus::ObjectManager;
+use std::collections::HashMap;
+
+/// An object returned by `GetManagedObjects`, with typed views of the properties of the
+/// interfaces it implements.
+#[derive(Debug, Default)]
+pub struct BluezObject {
+    /// The properties of each interface which the object implements, keyed by interface name.
+    pub interfaces: HashMap<String, arg::PropMap>,
+}
+
+impl BluezObject {
+    /// Get the `org.bluez.Adapter1` properties of the object, if it implements that interface.
+    pub fn adapter1(&self) -> Option<OrgBluezAdapter1Properties<'_>> {
+        OrgBluezAdapter1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.AdminPolicyStatus1` properties of the object, if it implements that interface.
+    pub fn adminpolicystatus1(&self) -> Option<OrgBluezAdminPolicyStatus1Properties<'_>> {
+        OrgBluezAdminPolicyStatus1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.AdvertisementMonitor1` properties of the object, if it implements that interface.
+    pub fn advertisementmonitor1(&self) -> Option<OrgBluezAdvertisementMonitor1Properties<'_>> {
+        OrgBluezAdvertisementMonitor1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.AdvertisementMonitorManager1` properties of the object, if it implements that interface.
+    pub fn advertisementmonitormanager1(
+        &self,
+    ) -> Option<OrgBluezAdvertisementMonitorManager1Properties<'_>> {
+        OrgBluezAdvertisementMonitorManager1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.Battery1` properties of the object, if it implements that interface.
+    pub fn battery1(&self) -> Option<OrgBluezBattery1Properties<'_>> {
+        OrgBluezBattery1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.BatteryProvider1` properties of the object, if it implements that interface.
+    pub fn batteryprovider1(&self) -> Option<OrgBluezBatteryProvider1Properties<'_>> {
+        OrgBluezBatteryProvider1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.Device1` properties of the object, if it implements that interface.
+    pub fn device1(&self) -> Option<OrgBluezDevice1Properties<'_>> {
+        OrgBluezDevice1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.GattCharacteristic1` properties of the object, if it implements that interface.
+    pub fn gattcharacteristic1(&self) -> Option<OrgBluezGattCharacteristic1Properties<'_>> {
+        OrgBluezGattCharacteristic1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.GattDescriptor1` properties of the object, if it implements that interface.
+    pub fn gattdescriptor1(&self) -> Option<OrgBluezGattDescriptor1Properties<'_>> {
+        OrgBluezGattDescriptor1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.GattService1` properties of the object, if it implements that interface.
+    pub fn gattservice1(&self) -> Option<OrgBluezGattService1Properties<'_>> {
+        OrgBluezGattService1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.HealthChannel1` properties of the object, if it implements that interface.
+    pub fn healthchannel1(&self) -> Option<OrgBluezHealthChannel1Properties<'_>> {
+        OrgBluezHealthChannel1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.HealthDevice1` properties of the object, if it implements that interface.
+    pub fn healthdevice1(&self) -> Option<OrgBluezHealthDevice1Properties<'_>> {
+        OrgBluezHealthDevice1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.LEAdvertisement1` properties of the object, if it implements that interface.
+    pub fn leadvertisement1(&self) -> Option<OrgBluezLEAdvertisement1Properties<'_>> {
+        OrgBluezLEAdvertisement1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.LEAdvertisingManager1` properties of the object, if it implements that interface.
+    pub fn leadvertisingmanager1(&self) -> Option<OrgBluezLEAdvertisingManager1Properties<'_>> {
+        OrgBluezLEAdvertisingManager1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.Media1` properties of the object, if it implements that interface.
+    pub fn media1(&self) -> Option<OrgBluezMedia1Properties<'_>> {
+        OrgBluezMedia1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.MediaControl1` properties of the object, if it implements that interface.
+    pub fn mediacontrol1(&self) -> Option<OrgBluezMediaControl1Properties<'_>> {
+        OrgBluezMediaControl1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.MediaEndpoint1` properties of the object, if it implements that interface.
+    pub fn mediaendpoint1(&self) -> Option<OrgBluezMediaEndpoint1Properties<'_>> {
+        OrgBluezMediaEndpoint1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.MediaFolder1` properties of the object, if it implements that interface.
+    pub fn mediafolder1(&self) -> Option<OrgBluezMediaFolder1Properties<'_>> {
+        OrgBluezMediaFolder1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.MediaItem1` properties of the object, if it implements that interface.
+    pub fn mediaitem1(&self) -> Option<OrgBluezMediaItem1Properties<'_>> {
+        OrgBluezMediaItem1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.MediaPlayer1` properties of the object, if it implements that interface.
+    pub fn mediaplayer1(&self) -> Option<OrgBluezMediaPlayer1Properties<'_>> {
+        OrgBluezMediaPlayer1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.MediaTransport1` properties of the object, if it implements that interface.
+    pub fn mediatransport1(&self) -> Option<OrgBluezMediaTransport1Properties<'_>> {
+        OrgBluezMediaTransport1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.Network1` properties of the object, if it implements that interface.
+    pub fn network1(&self) -> Option<OrgBluezNetwork1Properties<'_>> {
+        OrgBluezNetwork1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.SimAccess1` properties of the object, if it implements that interface.
+    pub fn simaccess1(&self) -> Option<OrgBluezSimAccess1Properties<'_>> {
+        OrgBluezSimAccess1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.mesh.Application1` properties of the object, if it implements that interface.
+    #[cfg(feature = "mesh")]
+    pub fn mesh_application1(&self) -> Option<OrgBluezMeshApplication1Properties<'_>> {
+        OrgBluezMeshApplication1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.mesh.Element1` properties of the object, if it implements that interface.
+    #[cfg(feature = "mesh")]
+    pub fn mesh_element1(&self) -> Option<OrgBluezMeshElement1Properties<'_>> {
+        OrgBluezMeshElement1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.mesh.Node1` properties of the object, if it implements that interface.
+    #[cfg(feature = "mesh")]
+    pub fn mesh_node1(&self) -> Option<OrgBluezMeshNode1Properties<'_>> {
+        OrgBluezMeshNode1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.obex.PhonebookAccess1` properties of the object, if it implements that interface.
+    #[cfg(feature = "obex")]
+    pub fn obex_phonebookaccess1(&self) -> Option<OrgBluezObexPhonebookAccess1Properties<'_>> {
+        OrgBluezObexPhonebookAccess1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.obex.Session1` properties of the object, if it implements that interface.
+    #[cfg(feature = "obex")]
+    pub fn obex_session1(&self) -> Option<OrgBluezObexSession1Properties<'_>> {
+        OrgBluezObexSession1Properties::from_interfaces(&self.interfaces)
+    }
+
+    /// Get the `org.bluez.obex.Transfer1` properties of the object, if it implements that interface.
+    #[cfg(feature = "obex")]
+    pub fn obex_transfer1(&self) -> Option<OrgBluezObexTransfer1Properties<'_>> {
+        OrgBluezObexTransfer1Properties::from_interfaces(&self.interfaces)
+    }
+}
+
+/// Call `GetManagedObjects` on the given `org.freedesktop.DBus.ObjectManager` proxy, and wrap
+/// each object in a `BluezObject`.
+pub async fn get_managed_objects<T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>>(
+    proxy: &nonblock::Proxy<'_, C>,
+) -> Result<HashMap<dbus::Path<'static>, BluezObject>, dbus::Error> {
+    let tree = proxy.get_managed_objects().await?;
+    Ok(tree
+        .into_iter()
+        .map(|(path, interfaces)| (path, BluezObject { interfaces }))
+        .collect())
+}
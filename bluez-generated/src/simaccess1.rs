@@ -0,0 +1,42 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.SimAccess1.xml --interfaces=org.bluez.SimAccess1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezSimAccess1 {
+    fn disconnect(&self) -> nonblock::MethodReply<()>;
+    fn connected(&self) -> nonblock::MethodReply<bool>;
+}
+
+pub const ORG_BLUEZ_SIM_ACCESS1_NAME: &str = "org.bluez.SimAccess1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezSimAccess1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezSimAccess1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.SimAccess1").map(Self)
+    }
+
+    pub fn connected(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "Connected").copied()
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezSimAccess1
+    for nonblock::Proxy<'a, C>
+{
+    fn disconnect(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.SimAccess1", "Disconnect", ())
+    }
+
+    fn connected(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.SimAccess1",
+            "Connected",
+        )
+    }
+}
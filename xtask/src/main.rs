@@ -0,0 +1,89 @@
+//! Development tasks for the workspace, run with `cargo xtask <task>`.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+const USAGE: &str = "Usage: cargo xtask regen [--introspect]
+
+Tasks:
+  regen    Regenerate bluez-generated from the spec XML in bluez-generated/specs, and report
+           which files changed.
+
+Options:
+  --introspect    Update the spec XML by introspecting the running BlueZ daemon first.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["regen"] => regen(false),
+        ["regen", "--introspect"] => regen(true),
+        _ => {
+            eprintln!("{}", USAGE);
+            exit(2);
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        exit(1);
+    }
+}
+
+/// Run `introspect.sh` to regenerate the bindings, then print a report of what changed.
+fn regen(introspect: bool) -> Result<(), String> {
+    let root = workspace_root();
+    let generated = root.join("bluez-generated");
+    run(Command::new(generated.join("introspect.sh"))
+        .env("INTROSPECT", if introspect { "1" } else { "0" })
+        .env("GENERATE", "1"))?;
+
+    println!();
+    let status = output(
+        Command::new("git")
+            .current_dir(&root)
+            .args(["status", "--porcelain", "--"])
+            .arg(&generated),
+    )?;
+    if status.trim().is_empty() {
+        println!("Generated bindings are unchanged.");
+    } else {
+        println!("Changed files:");
+        print!("{}", status);
+        println!();
+        run(Command::new("git")
+            .current_dir(&root)
+            .args(["diff", "--stat", "--"])
+            .arg(&generated))?;
+    }
+    Ok(())
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_owned()
+}
+
+fn run(command: &mut Command) -> Result<(), String> {
+    let status = command
+        .status()
+        .map_err(|e| format!("Failed to run {:?}: {}", command, e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{:?} failed with {}", command, status))
+    }
+}
+
+fn output(command: &mut Command) -> Result<String, String> {
+    let output = command
+        .output()
+        .map_err(|e| format!("Failed to run {:?}: {}", command, e))?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).map_err(|e| e.to_string())
+    } else {
+        Err(format!("{:?} failed with {}", command, output.status))
+    }
+}